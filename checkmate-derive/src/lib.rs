@@ -0,0 +1,109 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam, Index, Member};
+
+/// Generate a `<Struct>Checks` trait with a `field_<name>` method per field,
+/// implemented for `Should<Struct, S>`. Each method runs a check on the field
+/// and describes its failures with the field name, like `Checked::describe`.
+#[proc_macro_derive(Checkable)]
+pub fn derive_checkable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "Checkable can only be derived for structs"));
+    };
+    if let Fields::Unit = data.fields {
+        return Err(syn::Error::new_spanned(&input.ident, "Checkable needs a struct with fields"));
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let trait_name = format_ident!("{}Checks", name);
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let where_predicates = where_clause.map(|clause| &clause.predicates);
+
+    // The trait takes the parameters of the struct followed by the state.
+    // Defaults are dropped, they are only allowed on trailing parameters.
+    let mut trait_generics = input.generics.clone();
+    for param in trait_generics.params.iter_mut() {
+        match param {
+            GenericParam::Type(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Const(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    trait_generics.params.push(parse_quote!(__S));
+    trait_generics.where_clause = None;
+    let (impl_generics, trait_ty_generics, _) = trait_generics.split_for_impl();
+
+    let fields: Vec<_> = data.fields.iter().enumerate().collect();
+    let members: Vec<Member> = fields
+        .iter()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(*i)),
+        })
+        .collect();
+    let bindings: Vec<_> = (0..fields.len()).map(|i| format_ident!("__field_{}", i)).collect();
+
+    let mut declarations = Vec::new();
+    let mut methods = Vec::new();
+    for (i, field) in &fields {
+        let ty = &field.ty;
+        let label = match &field.ident {
+            Some(ident) => ident.unraw().to_string(),
+            None => i.to_string(),
+        };
+        let method = format_ident!("field_{}", label);
+        let binding = &bindings[*i];
+        let others: Vec<_> = bindings.iter().filter(|other| *other != binding).collect();
+        let signature = quote! {
+            fn #method<__R>(
+                self,
+                check: impl FnOnce(::checkmate::Checked<#ty>) -> __R,
+            ) -> <__S as ::checkmate::Nested<#name #ty_generics>>::Output
+            where
+                __S: ::checkmate::Nested<#name #ty_generics>,
+                __R: ::checkmate::prelude::CheckState<#ty>
+        };
+        declarations.push(quote!(#signature;));
+        methods.push(quote! {
+            #signature {
+                self.check_part(
+                    #label,
+                    |inner: #name #ty_generics| {
+                        let #name { #(#members: #bindings),* } = inner;
+                        (#binding, (#(#others,)*))
+                    },
+                    |#binding, (#(#others,)*)| #name { #(#members: #bindings),* },
+                    check,
+                )
+            }
+        });
+    }
+
+    Ok(quote! {
+        #vis trait #trait_name #trait_generics #where_clause {
+            #(#declarations)*
+        }
+
+        impl #impl_generics #trait_name #trait_ty_generics for ::checkmate::Should<#name #ty_generics, __S>
+        where
+            __S: ::checkmate::prelude::CheckState<#name #ty_generics>,
+            #where_predicates
+        {
+            #(#methods)*
+        }
+    })
+}
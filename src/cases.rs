@@ -0,0 +1,44 @@
+use crate::core::{failure_message, CheckState, Checked};
+
+/// Run the same check over every row of a table of cases.
+pub fn cases<T, I: IntoIterator<Item = T>>(rows: I) -> Cases<T, I> {
+    Cases { rows, label: None }
+}
+
+type Label<T> = Box<dyn Fn(&T) -> String>;
+
+pub struct Cases<T, I> {
+    rows: I,
+    label: Option<Label<T>>,
+}
+
+impl<T, I: IntoIterator<Item = T>> Cases<T, I> {
+    /// Name the rows in the failure report, e.g. by one of their fields.
+    pub fn label(self, label: impl Fn(&T) -> String + 'static) -> Self {
+        Self { label: Some(Box::new(label)), ..self }
+    }
+
+    /// Check every row, even after a failure, and report all failing rows
+    /// by index and label.
+    pub fn check<C, R: CheckState<C>>(self, mut check: impl FnMut(T) -> R) -> Checked<()> {
+        let mut total = 0;
+        let mut failures = Vec::new();
+        for (index, row) in self.rows.into_iter().enumerate() {
+            total += 1;
+            let name = match &self.label {
+                Some(label) => format!("row {index} ({})", label(&row)),
+                None => format!("row {index}"),
+            };
+            if let Some(message) = failure_message(&(check)(row)) {
+                failures.push(format!("{name}: {message}"));
+            }
+        }
+
+        if failures.is_empty() {
+            Checked::valid(())
+        } else {
+            let report = format!("{} of {total} cases failed:\n{}", failures.len(), failures.join("\n"));
+            Checked::invalid((), report)
+        }
+    }
+}
@@ -1,22 +1,24 @@
+use std::borrow::Borrow;
+
 use crate::core::{Checked, Should, CheckState};
 
-impl<S: CheckState<bool>> Should<bool, S> {
+impl<T: Borrow<bool>, S: CheckState<T>> Should<T, S> {
     pub fn be_true(self, message: &str) -> S {
-        self.match_predicate(|inner| -> Checked<bool> {
-            if inner {
+        self.match_predicate(|inner| -> Checked<T> {
+            if *inner.borrow() {
                 Checked::valid(inner)
             } else {
-                Checked::invalid(inner, format!("{message}"))
+                Checked::invalid(inner, message.to_string())
             }
         })
     }
 
     pub fn be_false(self, message: &str) -> S {
-        self.match_predicate(|inner| -> Checked<bool> {
-            if inner == false {
+        self.match_predicate(|inner| -> Checked<T> {
+            if !*inner.borrow() {
                 Checked::valid(inner)
             } else {
-                Checked::invalid(inner, format!("{message}"))
+                Checked::invalid(inner, message.to_string())
             }
         })
     }
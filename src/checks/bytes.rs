@@ -0,0 +1,97 @@
+use crate::core::{CheckState, Checked, CheckedChild, Should};
+use crate::diff::hex_diff;
+
+fn count_differences(expected: &[u8], actual: &[u8]) -> (usize, usize) {
+    let len = expected.len().max(actual.len());
+    let mut differences = (0..len).filter(|&i| expected.get(i) != actual.get(i));
+    let first = differences.next().unwrap_or(0);
+    (first, differences.count() + 1)
+}
+
+fn bytes_message(description: &str, expected: &[u8], actual: &[u8]) -> String {
+    let (first, count) = count_differences(expected, actual);
+    let bytes = if count == 1 { "byte differs" } else { "bytes differ" };
+    format!(
+        "Should {description} ({} bytes, got {}), {count} {bytes} starting at offset {first:#x}\n{}",
+        expected.len(),
+        actual.len(),
+        hex_diff(expected, actual).trim_end(),
+    )
+}
+
+// Checks for byte buffers, such as `Vec<u8>`, `[u8; N]` and `&[u8]`.
+// Mismatches are rendered as side-by-side hexdumps.
+impl<T: AsRef<[u8]>, S: CheckState<T>> Should<T, S> {
+    pub fn be_bytes(self, expected: impl AsRef<[u8]>) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            let (expected, actual) = (expected.as_ref(), inner.as_ref());
+            if expected == actual {
+                Checked::valid(inner)
+            } else {
+                let message = bytes_message("be the expected bytes", expected, actual);
+                Checked::invalid(inner, message)
+            }
+        })
+    }
+
+    pub fn start_with_bytes(self, prefix: impl AsRef<[u8]>) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            let (prefix, actual) = (prefix.as_ref(), inner.as_ref());
+            if actual.starts_with(prefix) {
+                Checked::valid(inner)
+            } else {
+                let start = &actual[..prefix.len().min(actual.len())];
+                let message = bytes_message("start with the expected bytes", prefix, start);
+                Checked::invalid(inner, message)
+            }
+        })
+    }
+
+    pub fn have_byte_at(self, index: usize, byte: u8) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            match inner.as_ref().get(index) {
+                Some(actual) if *actual == byte => Checked::valid(inner),
+                Some(actual) => {
+                    let message = format!("Should have byte {byte:#04x} at offset {index:#x}, got {actual:#04x}");
+                    Checked::invalid(inner, message)
+                }
+                None => {
+                    let len = inner.as_ref().len();
+                    let bytes = if len == 1 { "byte" } else { "bytes" };
+                    let message =
+                        format!("Should have byte {byte:#04x} at offset {index:#x}, but buffer has only {len} {bytes}");
+                    Checked::invalid(inner, message)
+                }
+            }
+        })
+    }
+
+    pub fn be_utf8(self) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            match std::str::from_utf8(inner.as_ref()) {
+                Ok(_) => Checked::valid(inner),
+                Err(err) => Checked::invalid(inner, utf8_message(&err)),
+            }
+        })
+    }
+
+    /// Continue with checks on the bytes decoded as UTF-8.
+    pub fn decode_utf8(self) -> CheckedChild<T, String> {
+        self.into_child(|state| {
+            let child = match &state {
+                Checked::Valid { value, .. } | Checked::Invalid { value, .. } => {
+                    match std::str::from_utf8(value.as_ref()) {
+                        Ok(decoded) => Checked::valid(decoded.to_string()),
+                        Err(err) => Checked::missing(utf8_message(&err)),
+                    }
+                }
+                Checked::Missing { .. } => return CheckedChild::orphan(state),
+            };
+            CheckedChild::new(state, child)
+        })
+    }
+}
+
+fn utf8_message(err: &std::str::Utf8Error) -> String {
+    format!("Should be valid UTF-8, invalid sequence at offset {:#x}", err.valid_up_to())
+}
@@ -0,0 +1,88 @@
+use std::borrow::Borrow;
+use std::ops::RangeInclusive;
+
+use crate::core::{CheckState, Checked, Should};
+
+/// A set of characters, used by `consist_only_of`. Implemented for
+/// predicates such as `char::is_alphanumeric`, inclusive ranges, and lists
+/// of allowed characters.
+pub trait CharClass {
+    fn contains_char(&self, c: char) -> bool;
+
+    /// Describe the class for failure messages.
+    fn describe(&self) -> String;
+}
+
+impl<F: Fn(char) -> bool> CharClass for F {
+    fn contains_char(&self, c: char) -> bool {
+        self(c)
+    }
+
+    fn describe(&self) -> String {
+        "the character class".to_string()
+    }
+}
+
+impl CharClass for RangeInclusive<char> {
+    fn contains_char(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl CharClass for &str {
+    fn contains_char(&self, c: char) -> bool {
+        self.contains(c)
+    }
+
+    fn describe(&self) -> String {
+        format!("characters of {self:?}")
+    }
+}
+
+impl<const N: usize> CharClass for [char; N] {
+    fn contains_char(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+
+    fn describe(&self) -> String {
+        format!("characters of {self:?}")
+    }
+}
+
+impl<T: Borrow<char>, S: CheckState<T>> Should<T, S> {
+    fn match_char(self, matches: impl FnOnce(char) -> bool, description: &str) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            let c = *inner.borrow();
+            if matches(c) {
+                Checked::valid(inner)
+            } else {
+                Checked::invalid(inner, format!("Should be {description}, got {c:?}"))
+            }
+        })
+    }
+
+    pub fn be_alphabetic(self) -> S {
+        self.match_char(char::is_alphabetic, "alphabetic")
+    }
+
+    pub fn be_ascii_digit(self) -> S {
+        self.match_char(|c| c.is_ascii_digit(), "an ASCII digit")
+    }
+
+    pub fn be_whitespace(self) -> S {
+        self.match_char(char::is_whitespace, "whitespace")
+    }
+
+    pub fn be_uppercase(self) -> S {
+        self.match_char(char::is_uppercase, "uppercase")
+    }
+
+    pub fn be_in_range(self, range: RangeInclusive<char>) -> S {
+        let description = format!("in range {range:?}");
+        self.match_char(|c| range.contains(&c), &description)
+    }
+}
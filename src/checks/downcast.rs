@@ -0,0 +1,91 @@
+use std::any::{type_name, Any};
+use std::error::Error;
+
+use crate::core::{CheckState, Checked, CheckedChild, Should};
+use crate::message::describe_any;
+
+macro_rules! impl_error_downcast {
+    ($boxed:ty, $($bounds:tt)+) => {
+        impl<S: CheckState<Box<$boxed>>> Should<Box<$boxed>, S> {
+            pub fn be_of_type<E: $($bounds)+>(self) -> CheckedChild<Box<$boxed>, E> {
+                self.into_child(|state| {
+                    let (state, err) = match state.split() {
+                        Ok(parts) => parts,
+                        Err(state) => return CheckedChild::orphan(state),
+                    };
+                    match err.downcast::<E>() {
+                        Ok(concrete) => CheckedChild::moved(state, Checked::valid(*concrete), |e| Box::new(e)),
+                        Err(err) => {
+                            let message = format!("Should be of type `{}`, got `{err:?}`", type_name::<E>());
+                            CheckedChild::new(state.replace_value(err), Checked::missing(message))
+                        }
+                    }
+                })
+            }
+        }
+
+        impl<'a, S: CheckState<&'a Box<$boxed>>> Should<&'a Box<$boxed>, S> {
+            pub fn be_of_type<E: $($bounds)+>(self) -> CheckedChild<&'a Box<$boxed>, &'a E> {
+                self.into_child(|state| {
+                    let child = match &state {
+                        Checked::Valid { value: err, .. } | Checked::Invalid { value: err, .. } => {
+                            match err.downcast_ref::<E>() {
+                                Some(concrete) => Checked::valid(concrete),
+                                None => Checked::missing(format!("Should be of type `{}`, got `{err:?}`", type_name::<E>())),
+                            }
+                        }
+                        Checked::Missing { .. } => state.to_missing(),
+                    };
+                    CheckedChild::new(state, child)
+                })
+            }
+        }
+    };
+}
+
+macro_rules! impl_any_downcast {
+    ($boxed:ty, $($bounds:tt)+) => {
+        impl<S: CheckState<Box<$boxed>>> Should<Box<$boxed>, S> {
+            pub fn be_of_type<V: $($bounds)+>(self) -> CheckedChild<Box<$boxed>, V> {
+                self.into_child(|state| {
+                    let (state, value) = match state.split() {
+                        Ok(parts) => parts,
+                        Err(state) => return CheckedChild::orphan(state),
+                    };
+                    match value.downcast::<V>() {
+                        Ok(concrete) => CheckedChild::moved(state, Checked::valid(*concrete), |v| Box::new(v)),
+                        Err(value) => {
+                            let message = format!("Should be of type `{}`, got {}", type_name::<V>(), describe_any(&*value));
+                            CheckedChild::new(state.replace_value(value), Checked::missing(message))
+                        }
+                    }
+                })
+            }
+        }
+
+        impl<'a, S: CheckState<&'a Box<$boxed>>> Should<&'a Box<$boxed>, S> {
+            pub fn be_of_type<V: $($bounds)+>(self) -> CheckedChild<&'a Box<$boxed>, &'a V> {
+                self.into_child(|state| {
+                    let child = match &state {
+                        Checked::Valid { value, .. } | Checked::Invalid { value, .. } => {
+                            match value.downcast_ref::<V>() {
+                                Some(concrete) => Checked::valid(concrete),
+                                None => {
+                                    let message = format!("Should be of type `{}`, got {}", type_name::<V>(), describe_any(&***value));
+                                    Checked::missing(message)
+                                }
+                            }
+                        }
+                        Checked::Missing { .. } => state.to_missing(),
+                    };
+                    CheckedChild::new(state, child)
+                })
+            }
+        }
+    };
+}
+
+impl_error_downcast!(dyn Error, Error + 'static);
+impl_error_downcast!(dyn Error + Send + Sync, Error + Send + Sync + 'static);
+impl_any_downcast!(dyn Any, Any);
+impl_any_downcast!(dyn Any + Send, Any + Send);
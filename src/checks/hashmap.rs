@@ -1,4 +1,6 @@
+use std::borrow::Borrow;
 use std::collections::HashMap;
+use std::fmt::Debug;
 
 use crate::message::format_value;
 use crate::{
@@ -6,32 +8,36 @@ use crate::{
     Checked,
 };
 
-impl<K, V, S: CheckState<HashMap<K, V>>> Should<HashMap<K, V>, S>
-where
-    K: std::cmp::PartialEq + 'static,
-    V: std::cmp::PartialEq + 'static,
-{
-    pub fn contain_key(self, key: K) -> S {
-        self.match_predicate(|inner| -> Checked<HashMap<K, V>> {
-            if inner.keys().any(|k| k == &key) {
+impl<M, S: CheckState<M>> Should<M, S> {
+    pub fn contain_key<K, V>(self, key: K) -> S
+    where
+        M: Borrow<HashMap<K, V>>,
+        K: std::cmp::PartialEq + Debug,
+    {
+        self.match_predicate(|inner| -> Checked<M> {
+            if inner.borrow().keys().any(|k| k == &key) {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(
                     inner,
-                    format!("Should contain key '{}'", format_value(&key)),
+                    format!("Should contain key {}", format_value(&key)),
                 )
             }
         })
     }
 
-    pub fn contain_value(self, value: V) -> S {
-        self.match_predicate(|inner| -> Checked<HashMap<K, V>> {
-            if inner.values().any(|k| k == &value) {
+    pub fn contain_value<K, V>(self, value: V) -> S
+    where
+        M: Borrow<HashMap<K, V>>,
+        V: std::cmp::PartialEq + Debug,
+    {
+        self.match_predicate(|inner| -> Checked<M> {
+            if inner.borrow().values().any(|k| k == &value) {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(
                     inner,
-                    format!("Should contain value '{}'", format_value(&value)),
+                    format!("Should contain value {}", format_value(&value)),
                 )
             }
         })
@@ -55,18 +61,20 @@ where
     //     })
     // }
 
-    pub fn contain_pair(self, key: K, value: V) -> S
+    pub fn contain_pair<K, V>(self, key: K, value: V) -> S
     where
-        K: std::cmp::Eq + std::hash::Hash,
+        M: Borrow<HashMap<K, V>>,
+        K: std::cmp::Eq + std::hash::Hash + Debug,
+        V: std::cmp::PartialEq + Debug,
     {
-        self.match_predicate(|inner| -> Checked<HashMap<K, V>> {
-            if inner.get(&key).map(|v| v == &value) == Some(true) {
+        self.match_predicate(|inner| -> Checked<M> {
+            if inner.borrow().get(&key).map(|v| v == &value) == Some(true) {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(
                     inner,
                     format!(
-                        "The HashMap should contain key-value pair: '{}, {}'",
+                        "The HashMap should contain key-value pair ({}, {})",
                         format_value(&key),
                         format_value(&value)
                     ),
@@ -1,4 +1,7 @@
+use std::borrow::Cow;
 use std::fmt::Debug;
+use std::iter::Map;
+use std::ops::Deref;
 
 use crate::core::{CheckState, Checked, Should, Times};
 use crate::message::format_value;
@@ -8,35 +11,142 @@ where
     T: IntoIterator + Clone,
     S: CheckState<T>,
 {
-    pub fn match_predicate_times<F, C>(self, predicate: F, times: C, message: String) -> S
+    /// Count the items that pass `predicate` and compare the count with
+    /// `times`. The message is only built when the check fails.
+    pub fn match_predicate_times<F, C>(self, predicate: F, times: C, message: impl FnOnce() -> String) -> S
     where
         F: Fn(T::Item) -> Checked<T::Item>,
         C: Times<T>,
     {
-        let check;
-        match self.check_state_ref() {
-            Checked::Valid { value } => {
-                let checks = value.clone().into_iter().map(|v| (predicate(v)));
-                check = times.check(value.to_owned(), message, checks)
-            }
-            Checked::Invalid { value, message } => {
-                check = Checked::invalid(value.to_owned(), message.clone())
-            }
-        };
-        self.propagate_check(check)
+        self.match_predicate(|inner| -> Checked<T> {
+            // The predicate takes the items by value, so they come from a
+            // single copy of the collection
+            let checks = inner.clone().into_iter().map(predicate);
+            times.check(inner, message, checks)
+        })
     }
 }
 
-impl<T, S, U> Should<T, S>
+/// Marks collections that are checked by value.
+pub struct Owned;
+
+/// Marks collections that are checked through a reference.
+pub struct Borrowed;
+
+/// Marks iterators, which are checked on a copy.
+pub struct Cloned;
+
+/// A collection that can be iterated by reference, whether the checked
+/// value owns it or borrows it. The marker keeps the implementations apart,
+/// so `Vec<T>`, `&Vec<T>` and iterators such as `0..5` are all supported.
+///
+/// Types that cannot be iterated by reference themselves can implement it
+/// with a marker of their own, to expose the collection they wrap.
+pub trait Collection<Marker> {
+    type Target: ?Sized;
+
+    fn collection(&self) -> impl Deref<Target = Self::Target> + '_;
+}
+
+impl<C> Collection<Owned> for C
 where
-    T: IntoIterator<Item = U> + Clone,
-    T::Item: PartialEq<U> + 'static,
-    U: Debug,
-    S: CheckState<T>,
+    for<'a> &'a C: IntoIterator,
+{
+    type Target = C;
+
+    fn collection(&self) -> impl Deref<Target = C> + '_ {
+        self
+    }
+}
+
+impl<C: ?Sized> Collection<Borrowed> for &C
+where
+    for<'a> &'a C: IntoIterator,
+{
+    type Target = C;
+
+    fn collection(&self) -> impl Deref<Target = C> + '_ {
+        *self
+    }
+}
+
+impl<I> Collection<Cloned> for I
+where
+    I: Iterator + Clone,
 {
-    pub fn have_count(self, count: usize) -> S {
+    type Target = Replay<I>;
+
+    fn collection(&self) -> impl Deref<Target = Replay<I>> + '_ {
+        // Copying the iterator is cheap, unlike collecting its items
+        Cow::<Replay<I>>::Owned(Replay(self.clone()))
+    }
+}
+
+/// An iterator that is iterated by reference by running a copy of it, see
+/// `Collection`. Its items are wrapped in `Replayed`.
+#[derive(Clone)]
+pub struct Replay<I>(I);
+
+impl<I: Iterator + Clone> IntoIterator for &Replay<I> {
+    type Item = Replayed<I::Item>;
+    type IntoIter = Map<I, fn(I::Item) -> Replayed<I::Item>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.clone().map(Replayed)
+    }
+}
+
+/// An item of a `Replay`, which is owned rather than borrowed.
+pub struct Replayed<X>(pub X);
+
+/// An item of a collection that is iterated by reference, compared with an
+/// expected value. Sequences yield references to their items and maps yield
+/// pairs of references, so both compare with owned values.
+pub trait ItemEq<U> {
+    fn item_eq(&self, expected: &U) -> bool;
+}
+
+impl<X: PartialEq<U> + ?Sized, U> ItemEq<U> for &X {
+    fn item_eq(&self, expected: &U) -> bool {
+        **self == *expected
+    }
+}
+
+impl<K: PartialEq<A> + ?Sized, V: PartialEq<B> + ?Sized, A, B> ItemEq<(A, B)> for (&K, &V) {
+    fn item_eq(&self, expected: &(A, B)) -> bool {
+        *self.0 == expected.0 && *self.1 == expected.1
+    }
+}
+
+impl<X: PartialEq<U>, U> ItemEq<U> for Replayed<X> {
+    fn item_eq(&self, expected: &U) -> bool {
+        self.0 == *expected
+    }
+}
+
+// The checks below iterate the collection by reference, so only iterators
+// are copied.
+impl<T, S: CheckState<T>> Should<T, S> {
+    pub fn contain<M, U, C>(self, value: U, times: C) -> S
+    where
+        T: Collection<M>,
+        for<'a> &'a T::Target: IntoIterator<Item: ItemEq<U>>,
+        U: Debug,
+        C: Times<T>,
+    {
         self.match_predicate(|inner| -> Checked<T> {
-            if inner.clone().into_iter().count() == count {
+            let count = (&*inner.collection()).into_iter().filter(|x| x.item_eq(&value)).count();
+            times.check_count(inner, || format!("Iterator should contain {}", format_value(&value)), count)
+        })
+    }
+
+    pub fn have_count<M>(self, count: usize) -> S
+    where
+        T: Collection<M>,
+        for<'a> &'a T::Target: IntoIterator,
+    {
+        self.match_predicate(|inner| -> Checked<T> {
+            if (&*inner.collection()).into_iter().count() == count {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(
@@ -47,78 +157,85 @@ where
         })
     }
 
-    pub fn be_empty(self) -> S {
+    pub fn be_empty<M>(self) -> S
+    where
+        T: Collection<M>,
+        for<'a> &'a T::Target: IntoIterator,
+    {
         self.match_predicate(|inner| -> Checked<T> {
-            if inner.clone().into_iter().count() == 0 {
+            if (&*inner.collection()).into_iter().next().is_none() {
                 Checked::valid(inner)
             } else {
-                Checked::invalid(inner, format!("Iterator should be empty"))
+                Checked::invalid(inner, "Iterator should be empty".to_string())
             }
         })
     }
 
-    pub fn not_be_empty(self) -> S {
+    pub fn not_be_empty<M>(self) -> S
+    where
+        T: Collection<M>,
+        for<'a> &'a T::Target: IntoIterator,
+    {
         self.match_predicate(|inner| -> Checked<T> {
-            if inner.clone().into_iter().count() > 0 {
+            if (&*inner.collection()).into_iter().next().is_some() {
                 Checked::valid(inner)
             } else {
-                Checked::invalid(inner, format!("Iterator should not be empty"))
+                Checked::invalid(inner, "Iterator should not be empty".to_string())
             }
         })
     }
 
-    pub fn contain<C: Times<T>>(self, value: U, times: C) -> S {
-        let predicate = |val: T::Item| -> Checked<T::Item> {
-            if val == value {
+    pub fn contain_any_of<M, U>(self, values: impl IntoIterator<Item = U> + Debug + Clone) -> S
+    where
+        T: Collection<M>,
+        for<'a> &'a T::Target: IntoIterator<Item: ItemEq<U>>,
+    {
+        self.match_predicate(|val| -> Checked<T> {
+            let found = {
+                let collection = val.collection();
+                values.clone().into_iter().any(|value| (&*collection).into_iter().any(|x| x.item_eq(&value)))
+            };
+            if found {
                 Checked::valid(val)
             } else {
-                Checked::invalid(
-                    val,
-                    format!("Iterator should contain {}", format_value(&value)),
-                )
-            }
-        };
-
-        self.match_predicate_times(
-            predicate,
-            times,
-            format!("Iterator should contain {}", format_value(&value)),
-        )
-    }
-
-    pub fn contain_any_of(self, values: impl IntoIterator<Item = U> + 'static) -> S {
-        let msg = format!("Iterator should contain any of {}", format_value(&values));
-        self.match_predicate(|val| -> Checked<T> {
-            for value in values.into_iter() {
-                if val.clone().into_iter().any(|x| x == value) {
-                    return Checked::valid(val);
-                };
+                Checked::invalid(val, format!("Iterator should contain any of {values:?}"))
             }
-            Checked::invalid(val, msg)
         })
     }
 
-    pub fn contain_all_of(self, values: impl IntoIterator<Item = U> + 'static) -> S {
-        let msg = format!("Iterator should contain all of {}", format_value(&values));
+    pub fn contain_all_of<M, U>(self, values: impl IntoIterator<Item = U> + Debug + Clone) -> S
+    where
+        T: Collection<M>,
+        for<'a> &'a T::Target: IntoIterator<Item: ItemEq<U>>,
+    {
         self.match_predicate(|val| -> Checked<T> {
-            for value in values.into_iter() {
-                if !val.clone().into_iter().any(|x| x == value) {
-                    return Checked::invalid(val, msg);
-                };
+            let found = {
+                let collection = val.collection();
+                values.clone().into_iter().all(|value| (&*collection).into_iter().any(|x| x.item_eq(&value)))
+            };
+            if found {
+                Checked::valid(val)
+            } else {
+                Checked::invalid(val, format!("Iterator should contain all of {values:?}"))
             }
-            Checked::valid(val)
         })
     }
 
-    pub fn contain_none_of(self, values: impl IntoIterator<Item = U> + 'static) -> S {
-        let msg = format!("Iterator should contain none of {}", format_value(&values));
+    pub fn contain_none_of<M, U>(self, values: impl IntoIterator<Item = U> + Debug + Clone) -> S
+    where
+        T: Collection<M>,
+        for<'a> &'a T::Target: IntoIterator<Item: ItemEq<U>>,
+    {
         self.match_predicate(|val| -> Checked<T> {
-            for value in values.into_iter() {
-                if val.clone().into_iter().any(|x| x == value) {
-                    return Checked::invalid(val, msg);
-                };
+            let found = {
+                let collection = val.collection();
+                values.clone().into_iter().any(|value| (&*collection).into_iter().any(|x| x.item_eq(&value)))
+            };
+            if found {
+                Checked::invalid(val, format!("Iterator should contain none of {values:?}"))
+            } else {
+                Checked::valid(val)
             }
-            Checked::valid(val)
         })
     }
 }
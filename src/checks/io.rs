@@ -0,0 +1,46 @@
+use std::borrow::Borrow;
+use std::io::{self, ErrorKind};
+
+use crate::core::{CheckState, Checked, Should};
+
+impl<T: Borrow<io::Error>, S: CheckState<T>> Should<T, S> {
+    pub fn have_kind(self, kind: ErrorKind) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            let err = inner.borrow();
+            if err.kind() == kind {
+                Checked::valid(inner)
+            } else {
+                let message = format!("Error kind should be {kind:?}, got {:?}", err.kind());
+                Checked::invalid(inner, message)
+            }
+        })
+    }
+
+    pub fn have_raw_os_error(self, code: i32) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            match inner.borrow().raw_os_error() {
+                Some(raw) if raw == code => Checked::valid(inner),
+                Some(raw) => Checked::invalid(inner, format!("Raw OS error should be {code}, got {raw}")),
+                None => Checked::invalid(inner, format!("Raw OS error should be {code}, got none")),
+            }
+        })
+    }
+}
+
+impl<R, S: CheckState<R>> Should<R, S> {
+    pub fn fail_with_kind<T>(self, kind: ErrorKind) -> S
+    where
+        R: Borrow<io::Result<T>>,
+    {
+        self.match_predicate(|inner| -> Checked<R> {
+            match inner.borrow() {
+                Err(err) if err.kind() == kind => Checked::valid(inner),
+                Err(err) => {
+                    let message = format!("Error kind should be {kind:?}, got {:?}", err.kind());
+                    Checked::invalid(inner, message)
+                }
+                Ok(_) => Checked::invalid(inner, format!("Should be Error with kind {kind:?}, got Ok")),
+            }
+        })
+    }
+}
@@ -1,4 +1,4 @@
-mod into_iterator;
+pub(crate) mod into_iterator;
 mod option;
 mod partialeq;
 mod string;
@@ -6,4 +6,12 @@ mod bool;
 mod partialord;
 mod hashmap;
 mod result;
-mod error;
\ No newline at end of file
+mod error;
+mod downcast;
+mod io;
+mod pattern;
+mod panic;
+mod snapshot;
+mod slice;
+mod bytes;
+pub(crate) mod char;
\ No newline at end of file
@@ -1,40 +1,75 @@
+use std::borrow::Borrow;
 use std::fmt::Debug;
 
-use crate::{core::{CheckState, Checked, Should}, message::format_value};
+use crate::{core::{CheckState, Checked, CheckedChild, Should}, message::format_value};
 
-impl<T, S: CheckState<Option<T>>> Should<Option<T>, S> {
-    pub fn be_none(self) -> S {
-        self.match_predicate(|inner| -> Checked<Option<T>> {
-            if inner.is_none() {
-                Checked::valid(None)
+impl<O, S: CheckState<O>> Should<O, S> {
+    pub fn be_none<T>(self) -> S
+    where
+        O: Borrow<Option<T>>,
+    {
+        self.match_predicate(|inner| -> Checked<O> {
+            if inner.borrow().is_none() {
+                Checked::valid(inner)
             } else {
                 Checked::invalid(inner, "Option should be None".to_string())
             }
         })
     }
 
-    pub fn not_be_none(self) -> S {
-        self.match_predicate(|inner| -> Checked<Option<T>> {
-            if inner.is_none() {
-                Checked::invalid(None, "Option should not be None".to_string())
+    pub fn not_be_none<T>(self) -> S
+    where
+        O: Borrow<Option<T>>,
+    {
+        self.match_predicate(|inner| -> Checked<O> {
+            if inner.borrow().is_none() {
+                Checked::invalid(inner, "Option should not be None".to_string())
             } else {
                 Checked::valid(inner)
             }
         })
     }
 
-    pub fn be_some_with_value<V: PartialEq<T> + Debug>(self, value: V) -> S 
+    pub fn be_some_with_value<T, V>(self, value: V) -> S 
     where
-        V: 'static
+        O: Borrow<Option<T>>,
+        V: PartialEq<T> + Debug
     {
-        self.match_predicate(|inner| -> Checked<Option<T>> {
-            if inner.is_none() {
-                Checked::invalid(inner, format!("Option should be Some({})", format_value(&value)))
-            } else if &value != inner.as_ref().unwrap() {
-                Checked::invalid(inner, format!("Option should be Some({})", format_value(&value)))
-            } else {
-                Checked::valid(inner)
+        self.match_predicate(|inner| -> Checked<O> {
+            match inner.borrow() {
+                Some(v) if &value == v => Checked::valid(inner),
+                _ => Checked::invalid(inner, format!("Option should be Some({})", format_value(&value))),
             }
         })
     }
+}
+
+impl<T, S: CheckState<Option<T>>> Should<Option<T>, S> {
+    pub fn be_some_and(self) -> CheckedChild<Option<T>, T> {
+        self.into_child(|state| {
+            let (state, inner) = match state.split() {
+                Ok(parts) => parts,
+                Err(state) => return CheckedChild::orphan(state),
+            };
+            match inner {
+                Some(value) => CheckedChild::moved(state, Checked::valid(value), Some),
+                None => CheckedChild::new(state.replace_value(None), Checked::missing("Option should be Some, got None".to_string())),
+            }
+        })
+    }
+}
+
+impl<'a, T, S: CheckState<&'a Option<T>>> Should<&'a Option<T>, S> {
+    pub fn be_some_and(self) -> CheckedChild<&'a Option<T>, &'a T> {
+        self.into_child(|state| {
+            let child = match &state {
+                Checked::Valid { value, .. } | Checked::Invalid { value, .. } => match value {
+                    Some(value) => Checked::valid(value),
+                    None => Checked::missing("Option should be Some, got None".to_string()),
+                },
+                Checked::Missing { .. } => state.to_missing(),
+            };
+            CheckedChild::new(state, child)
+        })
+    }
 }
\ No newline at end of file
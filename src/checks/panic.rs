@@ -0,0 +1,107 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use crate::core::{CheckState, Checked, CheckedChild, Should};
+use crate::message::describe_any;
+
+type Payload = Box<dyn Any + Send>;
+
+thread_local! {
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Run `f`, catching a panic without printing it. The panic hook is shared by
+/// all threads, so it is replaced only once by a hook that stays quiet for
+/// the threads that are running a check.
+fn catch_silently<R>(f: impl FnOnce() -> R) -> Result<R, Payload> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(|silenced| silenced.get()) {
+                default_hook(info)
+            }
+        }));
+    });
+    let previous = SILENCED.with(|silenced| silenced.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENCED.with(|silenced| silenced.set(previous));
+    result
+}
+
+fn payload_message(payload: &Payload) -> Option<&str> {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        Some(message)
+    } else {
+        payload.downcast_ref::<String>().map(|message| message.as_str())
+    }
+}
+
+impl<F, R, S> Should<F, S>
+where
+    F: FnMut() -> R,
+    S: CheckState<F>,
+{
+    pub fn panic(self) -> S {
+        self.match_predicate(|mut f| -> Checked<F> {
+            match catch_silently(&mut f) {
+                Err(_) => Checked::valid(f),
+                Ok(_) => Checked::invalid(f, "Should panic".to_string()),
+            }
+        })
+    }
+
+    pub fn panic_with_message_containing(self, message: &str) -> S {
+        self.match_predicate(|mut f| -> Checked<F> {
+            let payload = match catch_silently(&mut f) {
+                Err(payload) => payload,
+                Ok(_) => {
+                    let message = format!("Should panic with a message containing {message:?}, but did not panic");
+                    return Checked::invalid(f, message);
+                }
+            };
+            match payload_message(&payload) {
+                Some(actual) if actual.contains(message) => Checked::valid(f),
+                Some(actual) => {
+                    let message = format!("Should panic with a message containing {message:?}, got {actual:?}");
+                    Checked::invalid(f, message)
+                }
+                None => {
+                    let message = format!("Should panic with a message containing {message:?}, got {}", describe_any(&*payload));
+                    Checked::invalid(f, message)
+                }
+            }
+        })
+    }
+
+    pub fn not_panic(self) -> S {
+        self.match_predicate(|mut f| -> Checked<F> {
+            match catch_silently(&mut f) {
+                Ok(_) => Checked::valid(f),
+                Err(payload) => {
+                    let message = format!("Should not panic, got {}", describe_any(&*payload));
+                    Checked::invalid(f, message)
+                }
+            }
+        })
+    }
+
+    /// Check that the closure panics and continue with a chain on the
+    /// panic payload, e.g. to downcast a payload passed to `panic_any`.
+    pub fn panic_and(self) -> CheckedChild<F, Payload> {
+        self.into_child(|state| {
+            let (state, mut f) = match state.split() {
+                Ok(parts) => parts,
+                Err(state) => return CheckedChild::orphan(state),
+            };
+            let child = match catch_silently(&mut f) {
+                Err(payload) => Checked::valid(payload),
+                Ok(_) => Checked::missing("Should panic".to_string()),
+            };
+            CheckedChild::new(state.replace_value(f), child)
+        })
+    }
+}
@@ -1,30 +1,29 @@
 use std::fmt::Debug;
 
-use crate::{core::{CheckState, Checked, Should}, message::format_value};
+use crate::core::{CheckState, Checked, Should};
 
 impl<T, S: CheckState<T>> Should<T, S> {
-    pub fn be<U: PartialEq<T> + Debug>(self, value: U) -> S 
+    pub fn be<U>(self, value: U) -> S 
     where
-        U: 'static
+        U: PartialEq<T> + Debug
     {
         self.match_predicate(|inner| {
             if value == inner {
                 Checked::valid(inner)
             } else {
-                Checked::invalid(inner, format!("Should be '{}'", format_value(&value)))
+                Checked::invalid(inner, format!("Should be '{value:?}'"))
             }
         })
     }
 
-    pub fn be_one_of<U: PartialEq<T> + Debug>(self, values: impl IntoIterator<Item = U> + 'static) -> S {
-        let msg = format!("Should be one of {}", format_value(&values));
+    pub fn be_one_of<U: PartialEq<T>>(self, values: impl IntoIterator<Item = U> + Debug + Clone) -> S {
         self.match_predicate(|inner| -> Checked<T> {
-            for value in values.into_iter(){
+            for value in values.clone() {
                 if value == inner {
                     return Checked::valid(inner)
                 };
             };
-            Checked::invalid(inner, msg)
+            Checked::invalid(inner, format!("Should be one of {values:?}"))
         })
     }
 }
\ No newline at end of file
@@ -1,9 +1,11 @@
+use std::fmt::Debug;
+
 use crate::{core::{CheckState, Checked, Should}, message::format_value};
 
 impl<T, S: CheckState<T>> Should<T, S> {
-    pub fn be_greater_than<U: PartialOrd<T>>(self, value: U) -> S 
+    pub fn be_greater_than<U>(self, value: U) -> S 
     where
-        U: PartialOrd<T> + 'static
+        U: PartialOrd<T> + Debug
     {
         self.match_predicate(|inner| -> Checked<T> {
             if value < inner {
@@ -16,7 +18,7 @@ impl<T, S: CheckState<T>> Should<T, S> {
 
     pub fn be_smaller_than<U>(self, value: U) -> S 
     where
-        U: PartialOrd<T> + 'static
+        U: PartialOrd<T> + Debug
     {
         self.match_predicate(|inner| -> Checked<T> {
             if value > inner {
@@ -0,0 +1,18 @@
+use std::fmt::Debug;
+
+use crate::core::{CheckState, Checked, Should};
+
+impl<T: Debug, S: CheckState<T>> Should<T, S> {
+    /// Check the value against a pattern. Usually called through the
+    /// `should_match!` macro, which renders the pattern for the message.
+    pub fn match_pattern(self, pattern: &str, matches: impl FnOnce(&T) -> bool) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            if matches(&inner) {
+                Checked::valid(inner)
+            } else {
+                let message = format!("Should match pattern `{pattern}`, got {inner:?}");
+                Checked::invalid(inner, message)
+            }
+        })
+    }
+}
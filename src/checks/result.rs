@@ -1,23 +1,160 @@
-use crate::core::{Should, Checked, CheckState};
+use std::borrow::Borrow;
+use std::fmt::Debug;
 
-impl<T, E, S: CheckState<Result<T, E>>> Should<Result<T, E>, S> {
-    pub fn be_ok(self) -> S {
-        self.match_predicate(|inner| -> Checked<Result<T, E>> {
-            if inner.is_ok() {
+use crate::core::{Should, Checked, CheckedChild, CheckState};
+
+impl<R, S: CheckState<R>> Should<R, S> {
+    pub fn be_ok<T, E>(self) -> S
+    where
+        R: Borrow<Result<T, E>>,
+    {
+        self.match_predicate(|inner| -> Checked<R> {
+            if inner.borrow().is_ok() {
                 Checked::valid(inner)
             } else {
-                Checked::invalid(inner, format!("Should be Ok"))
+                Checked::invalid(inner, "Should be Ok".to_string())
             }
         })
     }
 
-    pub fn be_err(self) -> S {
-        self.match_predicate(|inner| -> Checked<Result<T, E>> {
-            if inner.is_err() {
+    pub fn be_err<T, E>(self) -> S
+    where
+        R: Borrow<Result<T, E>>,
+    {
+        self.match_predicate(|inner| -> Checked<R> {
+            if inner.borrow().is_err() {
                 Checked::valid(inner)
             } else {
-                Checked::invalid(inner, format!("Should be Error"))
+                Checked::invalid(inner, "Should be Error".to_string())
+            }
+        })
+    }
+
+    pub fn be_ok_with<T, E, V>(self, value: V) -> S
+    where
+        R: Borrow<Result<T, E>>,
+        V: PartialEq<T> + Debug,
+        T: Debug,
+        E: Debug,
+    {
+        self.match_predicate(|inner| -> Checked<R> {
+            match inner.borrow() {
+                Ok(v) if value == *v => Checked::valid(inner),
+                _ => {
+                    let message = format!("Should be Ok({value:?}), got {:?}", inner.borrow());
+                    Checked::invalid(inner, message)
+                }
+            }
+        })
+    }
+
+    pub fn be_err_with<T, E, V>(self, value: V) -> S
+    where
+        R: Borrow<Result<T, E>>,
+        V: PartialEq<E> + Debug,
+        T: Debug,
+        E: Debug,
+    {
+        self.match_predicate(|inner| -> Checked<R> {
+            match inner.borrow() {
+                Err(e) if value == *e => Checked::valid(inner),
+                _ => {
+                    let message = format!("Should be Err({value:?}), got {:?}", inner.borrow());
+                    Checked::invalid(inner, message)
+                }
+            }
+        })
+    }
+
+    pub fn be_err_satisfying<T, E>(self, predicate: impl FnOnce(&E) -> bool) -> S
+    where
+        R: Borrow<Result<T, E>>,
+        T: Debug,
+        E: Debug,
+    {
+        self.match_predicate(|inner| -> Checked<R> {
+            match inner.borrow() {
+                Err(e) if predicate(e) => Checked::valid(inner),
+                _ => {
+                    let message = format!("Should be Err satisfying the predicate, got {:?}", inner.borrow());
+                    Checked::invalid(inner, message)
+                }
+            }
+        })
+    }
+}
+
+impl<T, E, S: CheckState<Result<T, E>>> Should<Result<T, E>, S> {
+    pub fn be_ok_and(self) -> CheckedChild<Result<T, E>, T>
+    where
+        E: Debug,
+    {
+        self.into_child(|state| {
+            let (state, inner) = match state.split() {
+                Ok(parts) => parts,
+                Err(state) => return CheckedChild::orphan(state),
+            };
+            match inner {
+                Ok(value) => CheckedChild::moved(state, Checked::valid(value), Ok),
+                Err(err) => {
+                    let message = format!("Should be Ok, got Err({err:?})");
+                    CheckedChild::new(state.replace_value(Err(err)), Checked::missing(message))
+                }
+            }
+        })
+    }
+
+    pub fn be_err_and(self) -> CheckedChild<Result<T, E>, E>
+    where
+        T: Debug,
+    {
+        self.into_child(|state| {
+            let (state, inner) = match state.split() {
+                Ok(parts) => parts,
+                Err(state) => return CheckedChild::orphan(state),
+            };
+            match inner {
+                Err(err) => CheckedChild::moved(state, Checked::valid(err), Err),
+                Ok(value) => {
+                    let message = format!("Should be Error, got Ok({value:?})");
+                    CheckedChild::new(state.replace_value(Ok(value)), Checked::missing(message))
+                }
             }
         })
     }
+}
+
+
+impl<'a, T, E, S: CheckState<&'a Result<T, E>>> Should<&'a Result<T, E>, S> {
+    pub fn be_ok_and(self) -> CheckedChild<&'a Result<T, E>, &'a T>
+    where
+        E: Debug,
+    {
+        self.into_child(|state| {
+            let child = match &state {
+                Checked::Valid { value, .. } | Checked::Invalid { value, .. } => match value {
+                    Ok(value) => Checked::valid(value),
+                    Err(err) => Checked::missing(format!("Should be Ok, got Err({err:?})")),
+                },
+                Checked::Missing { .. } => state.to_missing(),
+            };
+            CheckedChild::new(state, child)
+        })
+    }
+
+    pub fn be_err_and(self) -> CheckedChild<&'a Result<T, E>, &'a E>
+    where
+        T: Debug,
+    {
+        self.into_child(|state| {
+            let child = match &state {
+                Checked::Valid { value, .. } | Checked::Invalid { value, .. } => match value {
+                    Err(err) => Checked::valid(err),
+                    Ok(value) => Checked::missing(format!("Should be Error, got Ok({value:?})")),
+                },
+                Checked::Missing { .. } => state.to_missing(),
+            };
+            CheckedChild::new(state, child)
+        })
+    }
 }
\ No newline at end of file
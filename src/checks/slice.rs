@@ -0,0 +1,145 @@
+use std::fmt::Debug;
+
+use crate::core::{CheckState, Checked, CheckedChild, Should};
+
+// Element chains for the common slice containers. Owned containers hand
+// out a clone of the element, borrowed ones a reference that lives as long
+// as the borrow, so `X: Clone` is only needed when the container is owned.
+macro_rules! impl_element_chains {
+    ($select:ident, $element:ty, $([$($generics:tt)*] $container:ty),+ $(,)?) => {$(
+        impl<$($generics)*, S: CheckState<$container>> Should<$container, S> {
+            /// Continue with a check on the element at `index`.
+            pub fn element_at(self, index: usize) -> CheckedChild<$container, $element> {
+                self.$select(|slice| match slice.get(index) {
+                    Some(element) => Ok(element),
+                    None => Err(format!("index {index} out of bounds (len {})", slice.len())),
+                })
+            }
+
+            /// Continue with a check on the first element.
+            pub fn first(self) -> CheckedChild<$container, $element> {
+                self.$select(|slice| slice.first().ok_or_else(|| "Should have a first element, got an empty slice".to_string()))
+            }
+
+            /// Continue with a check on the last element.
+            pub fn last(self) -> CheckedChild<$container, $element> {
+                self.$select(|slice| slice.last().ok_or_else(|| "Should have a last element, got an empty slice".to_string()))
+            }
+        }
+    )+};
+}
+
+impl_element_chains!(cloned_element, X, [X: Clone] Vec<X>, [X: Clone, const N: usize] [X; N], [X: Clone] Box<[X]>);
+impl_element_chains!(borrowed_element, &'a X, ['a, X] &'a [X], ['a, X] &'a Vec<X>, ['a, X, const N: usize] &'a [X; N]);
+
+// Checks for anything that can be viewed as a slice, such as arrays,
+// vectors and borrowed slices. Elements are accessed by index, so the
+// container is never cloned.
+impl<T, S: CheckState<T>> Should<T, S> {
+    pub fn have_len<X>(self, len: usize) -> S
+    where
+        T: AsRef<[X]>,
+    {
+        self.match_predicate(|inner| -> Checked<T> {
+            let actual = inner.as_ref().len();
+            if actual == len {
+                Checked::valid(inner)
+            } else {
+                Checked::invalid(inner, format!("Should have length {len}, got {actual}"))
+            }
+        })
+    }
+
+    fn cloned_element<X: Clone>(self, select: impl FnOnce(&[X]) -> Result<&X, String>) -> CheckedChild<T, X>
+    where
+        T: AsRef<[X]>,
+    {
+        self.into_child(|state| {
+            let child = match &state {
+                Checked::Valid { value, .. } | Checked::Invalid { value, .. } => match select(value.as_ref()) {
+                    Ok(element) => Checked::valid(element.clone()),
+                    Err(message) => Checked::missing(message),
+                },
+                Checked::Missing { .. } => return CheckedChild::orphan(state),
+            };
+            CheckedChild::new(state, child)
+        })
+    }
+
+    /// Check that `expected` appears as a contiguous run of elements.
+    pub fn contain_slice<X>(self, expected: &[X]) -> S
+    where
+        T: AsRef<[X]>,
+        X: PartialEq + Debug,
+    {
+        self.match_predicate(|inner| -> Checked<T> {
+            let slice = inner.as_ref();
+            if expected.is_empty() || slice.windows(expected.len()).any(|window| window == expected) {
+                Checked::valid(inner)
+            } else {
+                Checked::invalid(inner, format!("Should contain slice {expected:?}"))
+            }
+        })
+    }
+
+    /// Check that the elements equal `expected` in any order, counting
+    /// duplicates.
+    pub fn be_permutation_of<X>(self, expected: &[X]) -> S
+    where
+        T: AsRef<[X]>,
+        X: PartialEq + Debug,
+    {
+        self.match_predicate(|inner| -> Checked<T> {
+            let slice = inner.as_ref();
+            if is_permutation(slice, expected) {
+                Checked::valid(inner)
+            } else {
+                let message = format!("Should be a permutation of {expected:?}, got {slice:?}");
+                Checked::invalid(inner, message)
+            }
+        })
+    }
+}
+
+impl<'a, C: ?Sized, S: CheckState<&'a C>> Should<&'a C, S> {
+    fn borrowed_element<X>(self, select: impl FnOnce(&'a [X]) -> Result<&'a X, String>) -> CheckedChild<&'a C, &'a X>
+    where
+        C: AsRef<[X]>,
+    {
+        self.into_child(|state| {
+            let child = match &state {
+                Checked::Valid { value, .. } | Checked::Invalid { value, .. } => {
+                    let container: &'a C = value;
+                    match select(container.as_ref()) {
+                        Ok(element) => Checked::valid(element),
+                        Err(message) => Checked::missing(message),
+                    }
+                }
+                Checked::Missing { .. } => return CheckedChild::orphan(state),
+            };
+            CheckedChild::new(state, child)
+        })
+    }
+}
+
+/// Only `PartialEq` is available, so every element is matched against an
+/// unused element of `expected`.
+fn is_permutation<X: PartialEq>(slice: &[X], expected: &[X]) -> bool {
+    if slice.len() != expected.len() {
+        return false;
+    }
+    let mut used = vec![false; expected.len()];
+    slice.iter().all(|element| {
+        let found = expected
+            .iter()
+            .zip(used.iter())
+            .position(|(candidate, used)| !used && candidate == element);
+        match found {
+            Some(index) => {
+                used[index] = true;
+                true
+            }
+            None => false,
+        }
+    })
+}
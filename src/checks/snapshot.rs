@@ -0,0 +1,68 @@
+use std::fmt::{Debug, Display};
+use std::panic::Location;
+
+use crate::core::{CheckState, Checked, Should};
+use crate::snapshot::{check_file_snapshot, check_inline_snapshot};
+
+impl<T, S: CheckState<T>> Should<T, S> {
+    /// Compare the value, rendered by `render`, with a snapshot file.
+    #[track_caller]
+    pub fn match_snapshot_with(self, name: &str, render: impl FnOnce(&T) -> String) -> S {
+        let location = Location::caller();
+        self.match_predicate(|inner| -> Checked<T> {
+            match check_file_snapshot(location, name, &render(&inner)) {
+                Ok(()) => Checked::valid(inner),
+                Err(message) => Checked::invalid(inner, message),
+            }
+        })
+    }
+
+    /// Compare the pretty printed `Debug` output of the value with a
+    /// snapshot file.
+    #[track_caller]
+    pub fn match_snapshot(self, name: &str) -> S
+    where
+        T: Debug,
+    {
+        self.match_snapshot_with(name, |inner| format!("{inner:#?}"))
+    }
+
+    /// Compare the `Display` output of the value with a snapshot file.
+    #[track_caller]
+    pub fn match_display_snapshot(self, name: &str) -> S
+    where
+        T: Display,
+    {
+        self.match_snapshot_with(name, |inner| format!("{inner}"))
+    }
+
+    /// Compare the value, rendered by `render`, with a snapshot written in
+    /// the test source. In update mode mismatches are recorded for review,
+    /// see `snapshot::apply_inline_snapshots`.
+    #[track_caller]
+    pub fn match_inline_snapshot_with(self, expected: &str, render: impl FnOnce(&T) -> String) -> S {
+        let location = Location::caller();
+        self.match_predicate(|inner| -> Checked<T> {
+            match check_inline_snapshot(location, expected, &render(&inner)) {
+                Ok(()) => Checked::valid(inner),
+                Err(message) => Checked::invalid(inner, message),
+            }
+        })
+    }
+
+    #[track_caller]
+    pub fn match_inline_snapshot(self, expected: &str) -> S
+    where
+        T: Debug,
+    {
+        self.match_inline_snapshot_with(expected, |inner| format!("{inner:#?}"))
+    }
+
+    #[track_caller]
+    pub fn match_inline_display_snapshot(self, expected: &str) -> S
+    where
+        T: Display,
+    {
+        self.match_inline_snapshot_with(expected, |inner| format!("{inner}"))
+    }
+}
@@ -1,9 +1,10 @@
+use crate::checks::char::CharClass;
 use crate::core::{CheckState, Checked, Should};
 
-impl<S: CheckState<String>> Should<String, S> {
+impl<T: AsRef<str>, S: CheckState<T>> Should<T, S> {
     pub fn contain_substring(self, value: &str) -> S {
-        self.match_predicate(move |inner| -> Checked<String> {
-            if inner.contains(value) {
+        self.match_predicate(move |inner| -> Checked<T> {
+            if inner.as_ref().contains(value) {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(inner, format!("Should contain substring '{value}"))
@@ -15,14 +16,13 @@ impl<S: CheckState<String>> Should<String, S> {
         self,
         values: impl IntoIterator<Item = &'i str> + std::fmt::Debug + Clone,
     ) -> S {
-        let msg = format!("Should contain one of {values:?}");
-        self.match_predicate(|inner| -> Checked<String> {
-            for value in values.into_iter() {
-                if inner.clone().contains(value) {
+        self.match_predicate(|inner| -> Checked<T> {
+            for value in values.clone() {
+                if inner.as_ref().contains(value) {
                     return Checked::valid(inner);
                 };
             }
-            Checked::invalid(inner, msg)
+            Checked::invalid(inner, format!("Should contain one of {values:?}"))
         })
     }
 
@@ -30,20 +30,19 @@ impl<S: CheckState<String>> Should<String, S> {
         self,
         values: impl IntoIterator<Item = &'i str> + std::fmt::Debug + Clone,
     ) -> S {
-        let msg = format!("Should contain all of {values:?}");
-        self.match_predicate(|inner| -> Checked<String> {
-            for value in values.into_iter() {
-                if !inner.clone().contains(value) {
-                    return Checked::invalid(inner, msg);
+        self.match_predicate(|inner| -> Checked<T> {
+            for value in values.clone() {
+                if !inner.as_ref().contains(value) {
+                    return Checked::invalid(inner, format!("Should contain all of {values:?}"));
                 };
             }
             Checked::valid(inner)
         })
     }
 
-    pub fn start_with<'a>(self, value: &'a str) -> S {
-        self.match_predicate(|inner| -> Checked<String> {
-            if inner.starts_with(value) {
+    pub fn start_with(self, value: &str) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            if inner.as_ref().starts_with(value) {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(inner, format!("Should start with {value:?}"))
@@ -51,9 +50,9 @@ impl<S: CheckState<String>> Should<String, S> {
         })
     }
 
-    pub fn end_with<'a>(self, value: &'a str) -> S {
-        self.match_predicate(|inner| -> Checked<String> {
-            if inner.ends_with(value) {
+    pub fn end_with(self, value: &str) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            if inner.as_ref().ends_with(value) {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(inner, format!("Should end with {value:?}"))
@@ -62,8 +61,8 @@ impl<S: CheckState<String>> Should<String, S> {
     }
 
     pub fn have_length(self, length: usize) -> S {
-        self.match_predicate(|inner| -> Checked<String> {
-            if inner.len() == length {
+        self.match_predicate(|inner| -> Checked<T> {
+            if inner.as_ref().len() == length {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(inner, format!("Lenght should be {length}"))
@@ -72,8 +71,8 @@ impl<S: CheckState<String>> Should<String, S> {
     }
 
     pub fn be_empty_str(self) -> S {
-        self.match_predicate(|inner| -> Checked<String> {
-            if inner == "" {
+        self.match_predicate(|inner| -> Checked<T> {
+            if inner.as_ref().is_empty() {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(inner, String::from("Should be empty"))
@@ -82,8 +81,8 @@ impl<S: CheckState<String>> Should<String, S> {
     }
 
     pub fn not_be_empty_str(self) -> S {
-        self.match_predicate(|inner| -> Checked<String> {
-            if inner == "" {
+        self.match_predicate(|inner| -> Checked<T> {
+            if inner.as_ref().is_empty() {
                 Checked::invalid(inner, String::from("Should not be empty"))
             } else {
                 Checked::valid(inner)
@@ -92,8 +91,8 @@ impl<S: CheckState<String>> Should<String, S> {
     }
 
     pub fn be_equivalent_to(self, value: &str) -> S {
-        self.match_predicate(|inner| -> Checked<String> {
-            if value.to_lowercase() == inner.to_lowercase() {
+        self.match_predicate(|inner| -> Checked<T> {
+            if value.to_lowercase() == inner.as_ref().to_lowercase() {
                 Checked::valid(inner)
             } else {
                 Checked::invalid(inner, format!("Should be equivalent to {value:?}"))
@@ -102,12 +101,38 @@ impl<S: CheckState<String>> Should<String, S> {
     }
 
     pub fn not_be_equivalent_to(self, value: &str) -> S {
-        self.match_predicate(|inner| -> Checked<String> {
-            if value.to_lowercase() == inner.to_lowercase() {
+        self.match_predicate(|inner| -> Checked<T> {
+            if value.to_lowercase() == inner.as_ref().to_lowercase() {
                 Checked::invalid(inner, format!("Should not be equivalent to {value:?}"))
             } else {
                 Checked::valid(inner)
             }
         })
     }
+
+    /// Check that every character belongs to `class`, e.g. a range such as
+    /// `'a'..='z'` or a predicate such as `char::is_alphanumeric`.
+    pub fn consist_only_of(self, class: impl CharClass) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            match inner.as_ref().chars().enumerate().find(|(_, c)| !class.contains_char(*c)) {
+                None => Checked::valid(inner),
+                Some((index, c)) => {
+                    let message = format!("Should consist only of {}, got {c:?} at index {index}", class.describe());
+                    Checked::invalid(inner, message)
+                }
+            }
+        })
+    }
+
+    pub fn contain_no_control_characters(self) -> S {
+        self.match_predicate(|inner| -> Checked<T> {
+            match inner.as_ref().chars().enumerate().find(|(_, c)| c.is_control()) {
+                None => Checked::valid(inner),
+                Some((index, c)) => {
+                    let message = format!("Should contain no control characters, got {c:?} at index {index}");
+                    Checked::invalid(inner, message)
+                }
+            }
+        })
+    }
 }
@@ -1,12 +1,31 @@
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::panic::Location;
 use std::rc::Rc;
 
+use crate::report;
+
 pub trait CheckState<T> {
     fn get_state_as_ref(&self) -> &Checked<T>;
     fn get_state(self) -> Checked<T>;
     fn propagate(self, checked: Checked<T>) -> Self;
     fn propagate_predicate(self, check: impl FnOnce(T) -> Checked<T>) -> Self;
+
+    /// The names given with `describe` and `context`, outermost first.
+    fn trail(&self) -> &[String] {
+        &[]
+    }
+}
+
+/// A check state that can take the failure of a nested value, e.g. a field,
+/// along with the names of that value. A plain `Checked` becomes `Described`
+/// to keep the names.
+pub trait Nested<T>: CheckState<T> {
+    type Output: CheckState<T>;
+
+    /// Run `check` on the state. It returns the new state and, for a failure
+    /// of the nested value, the names that lead to it.
+    fn nest(self, check: impl FnOnce(Checked<T>) -> (Checked<T>, Vec<String>)) -> Self::Output;
 }
 
 pub trait Assertions<T> {
@@ -28,29 +47,41 @@ pub trait Directive<T, C: CheckState<T>> {
 
 pub trait Chain<T, S: CheckState<T>> {
     fn and(self) -> Should<T, S>;
-    fn which<C>(self, f: impl FnOnce(&T) -> Checked<C>) -> CheckedChild<T, C>;
-    fn then<C>(self, f: impl FnOnce(&T) -> Checked<C>) -> Checked<T>;
+    fn which<C, R: CheckState<C>>(self, f: impl FnOnce(&T) -> R) -> CheckedChild<T, C>;
+    fn then<C, R: CheckState<C>>(self, f: impl FnOnce(&T) -> R) -> Described<T>;
     fn also<C>(self, f: impl FnOnce(&T) -> C) -> CheckedChild<T, C>;
 }
 
-pub trait Times<T> 
-where
-    T: IntoIterator
-{
-    fn check<S: CheckState<T::Item>>(self, inner: T, message: String, checks: impl Iterator<Item = S>) -> Checked<T>;
+pub trait Times<T> {
+    /// Compare the number of items that matched with the expected number.
+    /// The message is only built when the check fails.
+    fn check_count(self, inner: T, message: impl FnOnce() -> String, count: usize) -> Checked<T>;
+
+    /// Compare the number of valid `checks` with the expected number.
+    fn check<S: CheckState<T::Item>>(self, inner: T, message: impl FnOnce() -> String, checks: impl Iterator<Item = S>) -> Checked<T>
+    where
+        T: IntoIterator,
+        Self: Sized,
+    {
+        let count = checks.filter(|check| check.get_state_as_ref().is_valid()).count();
+        self.check_count(inner, message, count)
+    }
     // fn check_rebuild<S: CheckState<T::Item>>(self, checks: impl Iterator<Item = S>, message: String) -> Checked<impl IntoIterator<Item = T::Item>>;
 }
 
 impl<T> CheckMateEntrypoint<T> for T {
     fn value(self) -> Checked<T> {
+        report::mark_start();
         Checked::valid(self)
     }
 
     fn value_as_ref(&self) -> Checked<&T> {
+        report::mark_start();
         Checked::valid(self)
     }
 
     fn value_rc(self) -> Checked<Rc<T>> {
+        report::mark_start();
         Checked::valid(Rc::new(self))
     }
 
@@ -58,30 +89,39 @@ impl<T> CheckMateEntrypoint<T> for T {
     where 
         T: Clone + Deref
     {
+        report::mark_start();
         let self_clone = self.clone();
         Checked::valid(self_clone)
     }
 }
 
 impl<T, S: CheckState<T>> Assertions<T> for S {
+    #[track_caller]
     fn assert_valid(&self) {
-        match self.get_state_as_ref() {
-            Checked::Valid { value: _ } => (),
-            Checked::Invalid { value: _, message } => panic!("{message}")
+        let message = failure_message(self);
+        report::record(Location::caller(), message.is_none(), message.as_deref());
+        if let Some(message) = message {
+            panic!("{message}");
         }
     }
 
+    #[track_caller]
     fn assert_invalid(&self) {
-        match self.get_state_as_ref() {
-            Checked::Valid { value: _ } => panic!("Should be invalid. Got valid value."),
-            Checked::Invalid { value: _, message } => panic!("{message}")
+        let message = failure_message(self);
+        report::record(Location::caller(), message.is_some(), message.as_deref());
+        if message.is_none() {
+            panic!("Should be invalid. Got valid value.");
         }
     }
 
+    #[track_caller]
     fn assert_invalid_with_message(&self, err_message: &str) {
-        match self.get_state_as_ref() {
-            Checked::Valid { value: _ } => self.assert_invalid(),
-            Checked::Invalid { value: _, message } => {
+        let message = failure_message(self);
+        let passed = message.as_deref() == Some(err_message);
+        report::record(Location::caller(), passed, message.as_deref());
+        match message {
+            None => panic!("Should be invalid. Got valid value."),
+            Some(message) => {
                 if message != err_message {
                     panic!("Found error value but with invalid message. Got {message}, expected {err_message}");
                 };
@@ -91,49 +131,52 @@ impl<T, S: CheckState<T>> Assertions<T> for S {
 }
 
 impl<T, S: CheckState<T>> Chain<T, S> for S {
-    fn and(self) -> Should<T, S> { 
+    fn and(self) -> Should<T, S> {
         Should::new(self)
     }
-    
-    fn which<C>(self, f: impl FnOnce(&T) -> Checked<C>) -> CheckedChild<T, C> {
-        match self.get_state() {
-            Checked::Valid { value } => {
-                let child = (f)(&value);
-                CheckedChild::new(Checked::valid(value), child)
-            },
-            Checked::Invalid { value, message } => {
-                let child = (f)(&value);
-                CheckedChild::new(Checked::invalid(value, message), child)
+
+    fn which<C, R: CheckState<C>>(self, f: impl FnOnce(&T) -> R) -> CheckedChild<T, C> {
+        let trail = self.trail().to_vec();
+        let state = self.get_state();
+        let child = match &state {
+            Checked::Valid { value } | Checked::Invalid { value, .. } => {
+                let child = (f)(value);
+                let names = child.trail().to_vec();
+                CheckedChild::new(state, child.get_state()).named(names)
             },
-        }
+            Checked::Missing { .. } => CheckedChild::orphan(state),
+        };
+        child.under(trail)
     }
-    
-    fn then<C>(self, f: impl FnOnce(&T) -> Checked<C>) -> Checked<T> {
-        match self.get_state() {
-            Checked::Invalid { value, message } => Checked::invalid(value, message),
+
+    fn then<C, R: CheckState<C>>(self, f: impl FnOnce(&T) -> R) -> Described<T> {
+        let trail = self.trail().to_vec();
+        let state = self.get_state();
+        match &state {
             Checked::Valid { value } => {
-                match (f)(&value) {
-                    Checked::Valid { value: _} => Checked::valid(value),
-                    Checked::Invalid { value: _, message } => Checked::invalid(value, message)
-                }
+                let child = (f)(value);
+                let names = child.trail().to_vec();
+                CheckedChild::new(state, child.get_state()).named(names).under(trail).done()
             },
+            Checked::Invalid { .. } | Checked::Missing { .. } => Described { state, trail },
         }
     }
 
     fn also<C>(self, f: impl FnOnce(&T) -> C) -> CheckedChild<T, C> {
-        match self.get_state() {
-            Checked::Valid { value } => {
-                let child = (f)(&value);
-                CheckedChild::new(Checked::valid(value), Checked::valid(child))
-            },
-            Checked::Invalid { value, message } => {
-                let child = (f)(&value);
-                CheckedChild::new(Checked::invalid(value, message), Checked::valid(child))
+        let trail = self.trail().to_vec();
+        let state = self.get_state();
+        let child = match &state {
+            Checked::Valid { value } | Checked::Invalid { value, .. } => {
+                let child = (f)(value);
+                CheckedChild::new(state, Checked::valid(child))
             },
-        }
+            Checked::Missing { .. } => CheckedChild::orphan(state),
+        };
+        child.under(trail)
     }
 }
 
+#[non_exhaustive]
 pub enum Checked<T> {
     Valid{
         value: T,
@@ -142,11 +185,16 @@ pub enum Checked<T> {
         value: T,
         message: String,
     },
+    /// There is no value to check, e.g. because converting the
+    /// parent value into the checked type failed.
+    Missing{
+        message: String,
+    },
 }
 
 impl<T> CheckState<T> for Checked<T> {
     fn get_state_as_ref(&self) -> &Checked<T> {
-        &self
+        self
     }
 
     fn get_state(self) -> Checked<T> {
@@ -160,11 +208,27 @@ impl<T> CheckState<T> for Checked<T> {
     fn propagate_predicate(self, check: impl FnOnce(T) -> Checked<T>) -> Self {
         match self {
             Self::Valid { value } => (check)(value),
-            Self::Invalid { value, message } => Self::invalid(value, message)
+            other => other,
         }
     }
 }
 
+/// Prefix a failure message with the names of the values it belongs to,
+/// e.g. "user > address: Should...".
+fn render(trail: &[String], message: &str) -> String {
+    if trail.is_empty() {
+        message.to_string()
+    } else {
+        format!("{}: {message}", trail.join(" > "))
+    }
+}
+
+/// The failure message of `state` prefixed with its trail, if it is not
+/// valid.
+pub(crate) fn failure_message<T>(state: &(impl CheckState<T> + ?Sized)) -> Option<String> {
+    state.get_state_as_ref().message().map(|message| render(state.trail(), message))
+}
+
 impl<T> Checked<T> {
     pub fn valid(value: T) -> Self {
         Self::Valid { value }
@@ -174,12 +238,88 @@ impl<T> Checked<T> {
         Self::Invalid { value, message }
     }
 
+    pub fn missing(message: String) -> Self {
+        Self::Missing { message }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid { .. })
+    }
+
+    /// The failure message, if the value is not valid.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Self::Valid { .. } => None,
+            Self::Invalid { message, .. } | Self::Missing { message } => Some(message),
+        }
+    }
+
     pub fn with_message(self, message: &str) -> Self {
+        self.with_message_fn(|| message.to_owned())
+    }
+
+    /// Replace the failure message with one built by `message`, which is
+    /// only called when the value is not valid.
+    pub fn with_message_fn(self, message: impl FnOnce() -> String) -> Self {
+        match self {
+            Self::Valid { value } => Self::Valid { value },
+            Self::Invalid { value, message: _ } => Self::Invalid { value, message: message() },
+            Self::Missing { message: _ } => Self::Missing { message: message() },
+        }
+    }
+
+    /// Name the value for failure messages, see `Described`.
+    pub fn describe(self, name: &str) -> Described<T> {
+        Described { state: self, trail: vec![name.to_string()] }
+    }
+
+    /// Add the circumstances of the checks to failure messages, e.g.
+    /// "when parsing header". Behaves like `describe`.
+    pub fn context(self, context: &str) -> Described<T> {
+        self.describe(context)
+    }
+
+    /// Mark a valid value as invalid. A value that already failed keeps
+    /// its original message.
+    pub fn invalidate(self, message: String) -> Self {
+        match self {
+            Self::Valid { value } => Self::Invalid { value, message },
+            other => other,
+        }
+    }
+
+    /// Separate the value from the outcome of the checks so far. Returns the
+    /// state itself if there is no value.
+    pub(crate) fn split(self) -> Result<(Checked<()>, T), Self> {
         match self {
-            Self::Valid { value } => Self::valid(value),
-            Self::Invalid { value, message: _} => Self::invalid(value, message.to_owned()),
+            Self::Valid { value } => Ok((Checked::Valid { value: () }, value)),
+            Self::Invalid { value, message } => Ok((Checked::Invalid { value: (), message }, value)),
+            missing => Err(missing),
         }
     }
+
+    pub(crate) fn replace_value<U>(self, value: U) -> Checked<U> {
+        match self {
+            Self::Valid { value: _ } => Checked::Valid { value },
+            Self::Invalid { value: _, message } => Checked::Invalid { value, message },
+            Self::Missing { message } => Checked::Missing { message },
+        }
+    }
+
+    /// A state without a value that carries the failure of this one, for
+    /// values derived from it.
+    pub(crate) fn to_missing<U>(&self) -> Checked<U> {
+        Checked::Missing { message: self.message().unwrap_or_default().to_string() }
+    }
+}
+
+impl<T> Nested<T> for Checked<T> {
+    type Output = Described<T>;
+
+    fn nest(self, check: impl FnOnce(Checked<T>) -> (Checked<T>, Vec<String>)) -> Described<T> {
+        let (state, trail) = check(self);
+        Described { state, trail }
+    }
 }
 
 impl<T> Directive<T, Checked<T>> for Checked<T> {
@@ -188,9 +328,106 @@ impl<T> Directive<T, Checked<T>> for Checked<T> {
     }
 }
 
+/// A checked value with the names given to it by `describe` and `context`,
+/// outermost first. The names prefix the failure message, so nested names
+/// read as a breadcrumb trail: "user > address: Should...". Names given to a
+/// valid value prefix the message of a later failure.
+pub struct Described<T> {
+    state: Checked<T>,
+    trail: Vec<String>,
+}
+
+impl<T> CheckState<T> for Described<T> {
+    fn get_state_as_ref(&self) -> &Checked<T> {
+        &self.state
+    }
+
+    fn get_state(self) -> Checked<T> {
+        self.state
+    }
+
+    fn propagate(self, checked: Checked<T>) -> Self {
+        Self { state: checked, trail: self.trail }
+    }
+
+    fn propagate_predicate(self, check: impl FnOnce(T) -> Checked<T>) -> Self {
+        Self { state: self.state.propagate_predicate(check), trail: self.trail }
+    }
+
+    fn trail(&self) -> &[String] {
+        &self.trail
+    }
+}
+
+impl<T> Nested<T> for Described<T> {
+    type Output = Self;
+
+    fn nest(mut self, check: impl FnOnce(Checked<T>) -> (Checked<T>, Vec<String>)) -> Self {
+        let (state, names) = check(self.state);
+        self.trail.extend(names);
+        Self { state, trail: self.trail }
+    }
+}
+
+impl<T> Directive<T, Described<T>> for Described<T> {
+    fn should(self) -> Should<T, Described<T>> {
+        Should::new(self)
+    }
+}
+
+impl<T> Described<T> {
+    pub fn is_valid(&self) -> bool {
+        self.state.is_valid()
+    }
+
+    /// The failure message prefixed with the trail, if the value is not
+    /// valid.
+    pub fn message(&self) -> Option<String> {
+        failure_message(self)
+    }
+
+    /// The names given with `describe` and `context`, outermost first.
+    pub fn trail(&self) -> &[String] {
+        &self.trail
+    }
+
+    /// Replace the failure message. The trail still prefixes the new message.
+    pub fn with_message(self, message: &str) -> Self {
+        self.with_message_fn(|| message.to_owned())
+    }
+
+    /// Replace the failure message lazily, see `Checked::with_message_fn`.
+    pub fn with_message_fn(self, message: impl FnOnce() -> String) -> Self {
+        Self { state: self.state.with_message_fn(message), trail: self.trail }
+    }
+
+    /// Name the value for failure messages, in front of the names it has.
+    pub fn describe(mut self, name: &str) -> Self {
+        self.trail.insert(0, name.to_string());
+        self
+    }
+
+    /// Add context to failure messages, see `Checked::context`.
+    pub fn context(self, context: &str) -> Self {
+        self.describe(context)
+    }
+}
+
+enum Parent<P, C> {
+    Kept(Checked<P>),
+    Moved {
+        state: Checked<()>,
+        rejoin: fn(C) -> P,
+    },
+}
+
 pub struct CheckedChild<P, C> {
-    parent: Checked<P>,
+    parent: Parent<P, C>,
     check_state: Checked<C>,
+    /// The names of the parent value followed by those of the child, see
+    /// `Described`.
+    trail: Vec<String>,
+    parent_len: usize,
 }
 
 impl<P, C> CheckState<C> for CheckedChild<P, C> {
@@ -203,14 +440,26 @@ impl<P, C> CheckState<C> for CheckedChild<P, C> {
     }
 
     fn propagate(self, checked: Checked<C>) -> Self {
-        Self::new(self.parent, checked)
+        Self { check_state: checked, ..self }
     }
 
     fn propagate_predicate(self, check: impl FnOnce(C) -> Checked<C>) -> Self {
-        match self.check_state {
-            Checked::Valid { value } => Self::new(self.parent, (check)(value)),
-            Checked::Invalid { value, message } => Self::new(self.parent, Checked::invalid(value, message)),
-        }
+        let check_state = self.check_state.propagate_predicate(check);
+        Self { check_state, ..self }
+    }
+
+    fn trail(&self) -> &[String] {
+        &self.trail
+    }
+}
+
+impl<P, C> Nested<C> for CheckedChild<P, C> {
+    type Output = Self;
+
+    fn nest(mut self, check: impl FnOnce(Checked<C>) -> (Checked<C>, Vec<String>)) -> Self {
+        let (check_state, names) = check(self.check_state);
+        self.trail.extend(names);
+        Self { check_state, ..self }
     }
 }
 
@@ -222,23 +471,100 @@ impl<P, C> Directive<C, CheckedChild<P, C>> for CheckedChild<P, C> {
 
 impl<P, C> CheckedChild<P, C> {
     pub fn new(parent: Checked<P>, child: Checked<C>) -> Self {
-        Self {parent, check_state: child}
+        Self { parent: Parent::Kept(parent), check_state: child, trail: Vec::new(), parent_len: 0 }
     }
 
-    pub fn with_message(self, message: &str) -> Self {
-        match self.check_state {
-            Checked::Valid { value } => Self::new(self.parent, Checked::valid(value)),
-            Checked::Invalid { value, message: _} => Self::new(self.parent, Checked::invalid(value, message.to_owned())),
+    /// Create a child that took ownership of the parent value, e.g. by
+    /// unwrapping or downcasting it. `state` holds the outcome of the parent
+    /// checks so far and `rejoin` rebuilds the parent value in `done`.
+    pub fn moved(state: Checked<()>, child: Checked<C>, rejoin: fn(C) -> P) -> Self {
+        Self { parent: Parent::Moved { state, rejoin }, check_state: child, trail: Vec::new(), parent_len: 0 }
+    }
+
+    /// Create a child of a parent that has no value.
+    pub fn missing(message: String) -> Self {
+        Self::orphan(Checked::missing(message))
+    }
+
+    /// Create a child of a parent that has no value, carrying its failure.
+    pub(crate) fn orphan(parent: Checked<P>) -> Self {
+        let child = parent.to_missing();
+        Self::new(parent, child)
+    }
+
+    /// Put the names of the parent value in front of the trail.
+    pub(crate) fn under(mut self, mut parent_trail: Vec<String>) -> Self {
+        self.parent_len += parent_trail.len();
+        parent_trail.append(&mut self.trail);
+        self.trail = parent_trail;
+        self
+    }
+
+    /// Name the child value with `names`, in front of the names it has.
+    fn named(mut self, names: Vec<String>) -> Self {
+        self.trail.splice(self.parent_len..self.parent_len, names);
+        self
+    }
+
+    /// The failure message of the parent checks, if any.
+    pub fn parent_message(&self) -> Option<&str> {
+        match &self.parent {
+            Parent::Kept(parent) => parent.message(),
+            Parent::Moved { state, rejoin: _ } => state.message(),
         }
     }
-    
-    pub fn done(self) -> Checked<P> {
-        match self.check_state {
-            Checked::Valid { value: _ } => self.parent,
-            Checked::Invalid { value: _, message } => {
-                self.parent.with_message(&message)
-            }
+
+    /// The names of the parent value, see `Described`.
+    pub(crate) fn parent_trail(&self) -> &[String] {
+        &self.trail[..self.parent_len]
+    }
+
+    pub fn with_message(self, message: &str) -> Self {
+        Self { check_state: self.check_state.with_message(message), ..self }
+    }
+
+    /// Replace the failure message of the child lazily, see
+    /// `Checked::with_message_fn`.
+    pub fn with_message_fn(self, message: impl FnOnce() -> String) -> Self {
+        Self { check_state: self.check_state.with_message_fn(message), ..self }
+    }
+
+    /// Name the child value for failure messages, see `Described`.
+    pub fn describe(self, name: &str) -> Self {
+        self.named(vec![name.to_string()])
+    }
+
+    /// Add context to failure messages of the child, see `Checked::context`.
+    pub fn context(self, context: &str) -> Self {
+        self.describe(context)
+    }
+
+    /// Return to the parent value. A failure of the child fails a valid
+    /// parent, with the names of both in front of the child message.
+    pub fn done(self) -> Described<P> {
+        let Self { parent, check_state, mut trail, parent_len } = self;
+        let parent_valid = match &parent {
+            Parent::Kept(state) => state.is_valid(),
+            Parent::Moved { state, rejoin: _ } => state.is_valid(),
+        };
+        if check_state.is_valid() || !parent_valid {
+            trail.truncate(parent_len);
         }
+        let state = match parent {
+            Parent::Kept(parent) => match check_state {
+                Checked::Valid { .. } => parent,
+                Checked::Invalid { value: _, message } | Checked::Missing { message } => parent.invalidate(message),
+            },
+            Parent::Moved { state, rejoin } => match check_state {
+                Checked::Valid { value } => state.replace_value(rejoin(value)),
+                Checked::Invalid { value, message } => state.replace_value(rejoin(value)).invalidate(message),
+                Checked::Missing { message } => match state {
+                    Checked::Valid { value: _ } => Checked::missing(message),
+                    failed => failed.to_missing(),
+                },
+            },
+        };
+        Described { state, trail }
     }
 }
 
@@ -267,4 +593,45 @@ impl<T, S: CheckState<T>> Should<T, S> {
     pub fn match_predicate(self, predicate: impl FnOnce(T) -> Checked<T>) -> S {
         self.inner.propagate_predicate(predicate)
     }
-}
\ No newline at end of file
+}
+impl<T, S: CheckState<T>> Should<T, S> {
+    /// Start a chain on a value derived from this one. `make` builds the
+    /// child from the state of the checks so far, and the names of this
+    /// value are kept for failures of the child.
+    pub fn into_child<C>(self, make: impl FnOnce(Checked<T>) -> CheckedChild<T, C>) -> CheckedChild<T, C> {
+        let trail = self.inner.trail().to_vec();
+        make(self.inner.get_state()).under(trail)
+    }
+
+    /// Check a part of a valid value, named `name` in failure messages.
+    /// `split` takes the part out of the value and `join` puts it back.
+    /// Used by `#[derive(Checkable)]`.
+    #[doc(hidden)]
+    pub fn check_part<F, Rest, R>(
+        self,
+        name: &str,
+        split: impl FnOnce(T) -> (F, Rest),
+        join: impl FnOnce(F, Rest) -> T,
+        check: impl FnOnce(Checked<F>) -> R,
+    ) -> S::Output
+    where
+        S: Nested<T>,
+        R: CheckState<F>,
+    {
+        self.inner.nest(|state| {
+            let value = match state {
+                Checked::Valid { value } => value,
+                failed => return (failed, Vec::new()),
+            };
+            let (part, rest) = split(value);
+            let checked = (check)(Checked::valid(part));
+            let mut names = vec![name.to_string()];
+            names.extend_from_slice(checked.trail());
+            match checked.get_state() {
+                Checked::Valid { value } => (Checked::valid(join(value, rest)), Vec::new()),
+                Checked::Invalid { value, message } => (Checked::invalid(join(value, rest), message), names),
+                Checked::Missing { message } => (Checked::missing(message), names),
+            }
+        })
+    }
+}
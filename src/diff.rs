@@ -0,0 +1,74 @@
+/// Render a line based diff between `expected` and `actual`. Removed lines
+/// are prefixed with `-`, added lines with `+`.
+pub fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    // lengths[i][j] is the length of the longest common subsequence of
+    // expected[i..] and actual[j..]
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            diff.push_str(&format!("  {}\n", expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            diff.push_str(&format!("- {}\n", expected[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("+ {}\n", actual[j]));
+            j += 1;
+        }
+    }
+    diff
+}
+
+const BYTES_PER_ROW: usize = 8;
+
+/// Render `expected` and `actual` as side-by-side hexdumps. Only rows that
+/// differ are shown, with `^^` under the differing bytes. Bytes missing
+/// from the shorter side are shown as `--`.
+pub fn hex_diff(expected: &[u8], actual: &[u8]) -> String {
+    let len = expected.len().max(actual.len());
+    let column = BYTES_PER_ROW * 3;
+    let mut diff = format!("{:8}  {:column$}{}\n", "offset", "expected", "actual");
+    for start in (0..len).step_by(BYTES_PER_ROW) {
+        let end = (start + BYTES_PER_ROW).min(len);
+        let differs: Vec<bool> = (start..end).map(|i| expected.get(i) != actual.get(i)).collect();
+        if !differs.contains(&true) {
+            continue;
+        }
+
+        let markers: String = differs.iter().map(|&differs| if differs { "^^ " } else { "   " }).collect();
+        diff.push_str(&format!(
+            "{start:08x}  {:column$}{}\n",
+            hex_row(expected, start, end),
+            hex_row(actual, start, end).trim_end(),
+        ));
+        let marker_row = format!("{:8}  {markers:column$}{}", "", markers.trim_end());
+        diff.push_str(marker_row.trim_end());
+        diff.push('\n');
+    }
+    diff
+}
+
+fn hex_row(bytes: &[u8], start: usize, end: usize) -> String {
+    (start..end)
+        .map(|i| match bytes.get(i) {
+            Some(byte) => format!("{byte:02x} "),
+            None => "-- ".to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,70 @@
+use std::fmt::Debug;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::{failure_message, CheckState, Checked};
+
+/// Repeatedly evaluate `fetch` until a check on its result becomes valid,
+/// e.g. to wait for a background worker to catch up.
+///
+/// The checks are passed to `check` as a closure rather than chained on a
+/// `should()`, since a chained check runs once and consumes its expected
+/// value, while the closure builds the chain again on every attempt:
+/// `eventually(|| fetch_count()).check(|count| count.value().should().be(10))`.
+pub fn eventually<T, F: FnMut() -> T>(fetch: F) -> Eventually<F> {
+    Eventually {
+        fetch,
+        within: Duration::from_secs(1),
+        every: Duration::from_millis(10),
+    }
+}
+
+pub struct Eventually<F> {
+    fetch: F,
+    within: Duration,
+    every: Duration,
+}
+
+impl<T, F: FnMut() -> T> Eventually<F> {
+    pub fn within(self, within: Duration) -> Self {
+        Self { within, ..self }
+    }
+
+    pub fn every(self, every: Duration) -> Self {
+        Self { every, ..self }
+    }
+
+    /// Run `check` on freshly fetched values until it is valid or the
+    /// timeout elapses. The value is always fetched at least once.
+    pub fn check<C: Debug, R: CheckState<C>>(mut self, check: impl Fn(T) -> R) -> Checked<C> {
+        let deadline = Instant::now() + self.within;
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let checked = (check)((self.fetch)());
+            let Some(message) = failure_message(&checked) else {
+                return checked.get_state();
+            };
+            let value = match checked.get_state() {
+                Checked::Invalid { value, .. } => Some(value),
+                Checked::Valid { .. } | Checked::Missing { .. } => None,
+            };
+
+            let now = Instant::now();
+            if now >= deadline {
+                let message = format!(
+                    "Should become valid within {:?}, still invalid after {attempts} attempts: {message}",
+                    self.within,
+                );
+                return match value {
+                    Some(value) => {
+                        let message = format!("{message} (last observed value {value:?})");
+                        Checked::invalid(value, message)
+                    }
+                    None => Checked::missing(message),
+                };
+            }
+            thread::sleep(self.every.min(deadline - now));
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt::Debug;
+
+/// A predicate on a single field, used by `match_fields!` in place of an
+/// expected value.
+pub trait FieldMatcher<A> {
+    fn matches(&self, actual: &A) -> bool;
+    fn describe(&self) -> String;
+}
+
+pub struct Gt<U>(U);
+pub struct Ge<U>(U);
+pub struct Lt<U>(U);
+pub struct Le<U>(U);
+pub struct Ne<U>(U);
+pub struct Anything;
+
+pub fn gt<U>(value: U) -> Gt<U> {
+    Gt(value)
+}
+
+pub fn ge<U>(value: U) -> Ge<U> {
+    Ge(value)
+}
+
+pub fn lt<U>(value: U) -> Lt<U> {
+    Lt(value)
+}
+
+pub fn le<U>(value: U) -> Le<U> {
+    Le(value)
+}
+
+pub fn ne<U>(value: U) -> Ne<U> {
+    Ne(value)
+}
+
+pub fn anything() -> Anything {
+    Anything
+}
+
+impl<A: PartialOrd<U>, U: Debug> FieldMatcher<A> for Gt<U> {
+    fn matches(&self, actual: &A) -> bool {
+        *actual > self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("> {:?}", self.0)
+    }
+}
+
+impl<A: PartialOrd<U>, U: Debug> FieldMatcher<A> for Ge<U> {
+    fn matches(&self, actual: &A) -> bool {
+        *actual >= self.0
+    }
+
+    fn describe(&self) -> String {
+        format!(">= {:?}", self.0)
+    }
+}
+
+impl<A: PartialOrd<U>, U: Debug> FieldMatcher<A> for Lt<U> {
+    fn matches(&self, actual: &A) -> bool {
+        *actual < self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("< {:?}", self.0)
+    }
+}
+
+impl<A: PartialOrd<U>, U: Debug> FieldMatcher<A> for Le<U> {
+    fn matches(&self, actual: &A) -> bool {
+        *actual <= self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("<= {:?}", self.0)
+    }
+}
+
+impl<A: PartialEq<U>, U: Debug> FieldMatcher<A> for Ne<U> {
+    fn matches(&self, actual: &A) -> bool {
+        *actual != self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("not {:?}", self.0)
+    }
+}
+
+impl<A> FieldMatcher<A> for Anything {
+    fn matches(&self, _actual: &A) -> bool {
+        true
+    }
+
+    fn describe(&self) -> String {
+        "anything".to_string()
+    }
+}
+
+/// A field and what it is expected to match. `match_fields!` calls
+/// `check_field` through a reference to a reference, so method resolution
+/// picks `MatchWithMatcher` when the expectation is a `FieldMatcher` and
+/// falls back to `MatchWithEq` otherwise.
+#[doc(hidden)]
+pub struct Field<'a, E, A> {
+    expected: &'a E,
+    actual: &'a A,
+}
+
+impl<'a, E, A> Field<'a, E, A> {
+    pub fn new(expected: &'a E, actual: &'a A) -> Self {
+        Self { expected, actual }
+    }
+}
+
+#[doc(hidden)]
+pub trait MatchWithMatcher {
+    fn check_field(&self) -> Result<(), String>;
+}
+
+impl<E: FieldMatcher<A>, A: Debug> MatchWithMatcher for &Field<'_, E, A> {
+    fn check_field(&self) -> Result<(), String> {
+        if self.expected.matches(self.actual) {
+            Ok(())
+        } else {
+            Err(format!("Should be {}, got {:?}", self.expected.describe(), self.actual))
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait MatchWithEq {
+    fn check_field(&self) -> Result<(), String>;
+}
+
+impl<E: PartialEq<A> + Debug, A: Debug> MatchWithEq for Field<'_, E, A> {
+    fn check_field(&self) -> Result<(), String> {
+        if *self.expected == *self.actual {
+            Ok(())
+        } else {
+            Err(format!("Should be {:?}, got {:?}", self.expected, self.actual))
+        }
+    }
+}
+
+/// Render a number of elements for `match_fields!` messages, e.g.
+/// "1 element" or "2 elements".
+#[doc(hidden)]
+pub fn elements(count: usize) -> String {
+    let noun = if count == 1 { "element" } else { "elements" };
+    format!("{count} {noun}")
+}
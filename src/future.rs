@@ -0,0 +1,89 @@
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+use crate::core::Checked;
+
+/// How long `should_complete_with` waits for the future to resolve.
+const COMPLETE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Checks on futures, which are polled on the current thread so that no
+/// runtime is needed.
+pub trait FutureEntrypoint: Future + Sized {
+    /// Start a check that the future resolves, bounded with `within`.
+    fn should_resolve(self) -> Resolve<Self>;
+
+    /// Block the current thread until the future resolves, then check the
+    /// output. The wait is bounded to one second, use
+    /// `should_resolve().within(..)` to choose another bound.
+    fn should_complete_with<C>(self, check: impl FnOnce(Checked<Self::Output>) -> Checked<C>) -> Checked<C>;
+
+    /// Check that the future is still pending after `duration`. The future
+    /// is kept, so it can be awaited or checked further.
+    fn should_stay_pending_for(self, duration: Duration) -> Checked<Pin<Box<Self>>>;
+}
+
+impl<F: Future> FutureEntrypoint for F {
+    fn should_resolve(self) -> Resolve<Self> {
+        Resolve { future: self }
+    }
+
+    fn should_complete_with<C>(self, check: impl FnOnce(Checked<Self::Output>) -> Checked<C>) -> Checked<C> {
+        match self.should_resolve().within(COMPLETE_TIMEOUT) {
+            Checked::Valid { value } => (check)(Checked::valid(value)),
+            failed => failed.to_missing(),
+        }
+    }
+
+    fn should_stay_pending_for(self, duration: Duration) -> Checked<Pin<Box<Self>>> {
+        let mut future = Box::pin(self);
+        match block_on(future.as_mut(), Instant::now() + duration) {
+            Poll::Pending => Checked::valid(future),
+            Poll::Ready(_) => Checked::invalid(
+                future,
+                format!("Future should stay pending for {duration:?}, but resolved"),
+            ),
+        }
+    }
+}
+
+pub struct Resolve<F> {
+    future: F,
+}
+
+impl<F: Future> Resolve<F> {
+    pub fn within(self, duration: Duration) -> Checked<F::Output> {
+        match block_on(pin!(self.future), Instant::now() + duration) {
+            Poll::Ready(output) => Checked::valid(output),
+            Poll::Pending => Checked::missing(format!("Future should resolve within {duration:?}")),
+        }
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Poll the future on the current thread, parking it between wake-ups,
+/// until it resolves or the deadline passes.
+fn block_on<F: Future>(mut future: Pin<&mut F>, deadline: Instant) -> Poll<F::Output> {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return Poll::Ready(output);
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return Poll::Pending;
+        }
+        thread::park_timeout(deadline - now);
+    }
+}
@@ -1,7 +1,24 @@
 mod core;
 mod checks;
 mod message;
+mod macros;
+mod eventually;
+mod diff;
+mod cases;
 
 pub mod prelude;
 pub mod times;
-pub use core::{Should, Checked, Times};
+pub mod future;
+pub mod snapshot;
+pub mod property;
+pub mod fields;
+pub mod report;
+pub mod summary;
+pub use core::{Should, Checked, CheckedChild, Described, Nested, Times};
+pub use eventually::{eventually, Eventually};
+pub use cases::{cases, Cases};
+pub use checks::char::CharClass;
+pub use checks::into_iterator::{Collection, Owned, Borrowed, Cloned, ItemEq, Replay, Replayed};
+
+#[cfg(feature = "derive")]
+pub use checkmate_derive::Checkable;
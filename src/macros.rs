@@ -0,0 +1,219 @@
+/// Check that a value matches a pattern, optionally with a guard.
+///
+/// The first argument is anything `.should()` can be called on. Bindings in
+/// the pattern are references to the checked value.
+///
+/// ```
+/// use checkmate::prelude::*;
+/// use checkmate::should_match;
+///
+/// #[derive(Debug)]
+/// enum State {
+///     Running { retries: u32 },
+///     Stopped,
+/// }
+///
+/// should_match!(State::Running { retries: 1 }.value(), State::Running { retries, .. } if *retries < 3)
+///     .assert_valid();
+///
+/// should_match!(State::Stopped.value(), State::Running { .. })
+///     .assert_invalid_with_message("Should match pattern `State::Running { .. }`, got Stopped");
+/// ```
+#[macro_export]
+macro_rules! should_match {
+    ($checked:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        $crate::prelude::Directive::should($checked).match_pattern(
+            concat!(stringify!($pattern) $(, " if ", stringify!($guard))?),
+            |value| matches!(value, $pattern $(if $guard)?),
+        )
+    };
+}
+
+/// Check a boolean expression. Comparisons (`==`, `!=`, `<`, `<=`, `>`,
+/// `>=`) at the top level of the expression are split into their operands,
+/// which are evaluated once and rendered with `Debug` in the failure message.
+///
+/// Operands containing a bare `<` or `>`, such as a turbofish, need to be
+/// wrapped in parentheses.
+///
+/// ```
+/// use checkmate::prelude::*;
+/// use checkmate::check;
+///
+/// let v = vec![1, 2];
+///
+/// check!(v.len() == 2).assert_valid();
+/// check!(v.len() > 2)
+///     .assert_invalid_with_message("Check `v.len() > 2` failed: left `v.len()` is 2, right `2` is 2");
+/// ```
+#[macro_export]
+macro_rules! check {
+    ($($expr:tt)+) => {
+        $crate::__check_logic!([$($expr)+] $($expr)+)
+    };
+}
+
+/// `&&` and `||` bind weaker than comparisons, so an expression containing
+/// them at the top level is checked as a whole.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_logic {
+    ([$($all:tt)+] && $($rest:tt)*) => { $crate::__check_bool!($($all)+) };
+    ([$($all:tt)+] || $($rest:tt)*) => { $crate::__check_bool!($($all)+) };
+    ([$($all:tt)+] $next:tt $($rest:tt)*) => { $crate::__check_logic!([$($all)+] $($rest)*) };
+    ([$($all:tt)+]) => { $crate::__check_split!([$($all)+] [] $($all)+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_split {
+    ([$($all:tt)+] [$($lhs:tt)+] == $($rhs:tt)+) => { $crate::__check_compare!([$($all)+] [$($lhs)+] == [$($rhs)+]) };
+    ([$($all:tt)+] [$($lhs:tt)+] != $($rhs:tt)+) => { $crate::__check_compare!([$($all)+] [$($lhs)+] != [$($rhs)+]) };
+    ([$($all:tt)+] [$($lhs:tt)+] <= $($rhs:tt)+) => { $crate::__check_compare!([$($all)+] [$($lhs)+] <= [$($rhs)+]) };
+    ([$($all:tt)+] [$($lhs:tt)+] >= $($rhs:tt)+) => { $crate::__check_compare!([$($all)+] [$($lhs)+] >= [$($rhs)+]) };
+    ([$($all:tt)+] [$($lhs:tt)+] < $($rhs:tt)+) => { $crate::__check_compare!([$($all)+] [$($lhs)+] < [$($rhs)+]) };
+    ([$($all:tt)+] [$($lhs:tt)+] > $($rhs:tt)+) => { $crate::__check_compare!([$($all)+] [$($lhs)+] > [$($rhs)+]) };
+    ([$($all:tt)+] [$($lhs:tt)*] $next:tt $($rest:tt)*) => { $crate::__check_split!([$($all)+] [$($lhs)* $next] $($rest)*) };
+    ([$($all:tt)+] [$($lhs:tt)*]) => { $crate::__check_bool!($($all)+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_compare {
+    ([$($all:tt)+] [$($lhs:tt)+] $op:tt [$($rhs:tt)+]) => {
+        match (&($($lhs)+), &($($rhs)+)) {
+            (lhs, rhs) => {
+                if *lhs $op *rhs {
+                    $crate::Checked::valid(true)
+                } else {
+                    $crate::Checked::invalid(false, format!(
+                        "Check `{}` failed: left `{}` is {:?}, right `{}` is {:?}",
+                        stringify!($($all)+),
+                        stringify!($($lhs)+),
+                        lhs,
+                        stringify!($($rhs)+),
+                        rhs,
+                    ))
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_bool {
+    ($($all:tt)+) => {{
+        let result: bool = $($all)+;
+        if result {
+            $crate::Checked::valid(true)
+        } else {
+            $crate::Checked::invalid(false, format!("Check `{}` failed", stringify!($($all)+)))
+        }
+    }};
+}
+
+/// Check a subset of the fields of a value and report every mismatching
+/// field. Fields can be compared with a value, with a matcher from
+/// `checkmate::fields` such as `gt(18)`, with `{ .. }` for the fields of a
+/// nested struct, or with `[ .. ]` for the elements of a vector or slice.
+///
+/// The first argument is anything `.should()` can be called on.
+///
+/// ```
+/// use checkmate::prelude::*;
+/// use checkmate::match_fields;
+/// use checkmate::fields::gt;
+///
+/// #[derive(Debug)]
+/// struct User {
+///     name: String,
+///     age: u32,
+///     emails: Vec<String>,
+/// }
+///
+/// let user = User { name: String::from("bob"), age: 17, emails: vec![] };
+///
+/// match_fields!(user.value(), { name: "bob", age: gt(18), emails: ["bob@example.com"] })
+///     .assert_invalid_with_message(
+///         "2 fields did not match:\nage: Should be > 18, got 17\nemails[0]: Should exist, got 0 elements",
+///     );
+/// ```
+#[macro_export]
+macro_rules! match_fields {
+    ($checked:expr, { $($fields:tt)* } $(,)?) => {
+        $crate::prelude::Directive::should($checked).match_predicate(|value| {
+            let mut failures: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+            $crate::__match_fields!(failures, value, "", { $($fields)* });
+            if failures.is_empty() {
+                $crate::Checked::valid(value)
+            } else {
+                let count = failures.len();
+                let noun = if count == 1 { "field" } else { "fields" };
+                $crate::Checked::invalid(value, format!("{count} {noun} did not match:\n{}", failures.join("\n")))
+            }
+        })
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_fields {
+    ($failures:ident, $value:expr, $path:expr, {}) => {};
+    ($failures:ident, $value:expr, $path:expr, { $field:ident : { $($inner:tt)* } $(, $($rest:tt)*)? }) => {
+        $crate::__match_fields!($failures, $value.$field, &format!("{}{}.", $path, stringify!($field)), { $($inner)* });
+        $crate::__match_fields!($failures, $value, $path, { $($($rest)*)? });
+    };
+    ($failures:ident, $value:expr, $path:expr, { $field:ident : [ $($elements:tt)* ] $(, $($rest:tt)*)? }) => {
+        {
+            let elements = &$value.$field[..];
+            let path = format!("{}{}", $path, stringify!($field));
+            let mut index = 0;
+            $crate::__match_elements!($failures, elements, path, index, [ $($elements)* ]);
+            if elements.len() > index {
+                $failures.push(format!("{path}: Should have {}, got {}", $crate::fields::elements(index), elements.len()));
+            }
+        }
+        $crate::__match_fields!($failures, $value, $path, { $($($rest)*)? });
+    };
+    ($failures:ident, $value:expr, $path:expr, { $field:ident : $expected:expr $(, $($rest:tt)*)? }) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::fields::{MatchWithEq as _, MatchWithMatcher as _};
+            if let Err(message) = (&&$crate::fields::Field::new(&$expected, &$value.$field)).check_field() {
+                $failures.push(format!("{}{}: {message}", $path, stringify!($field)));
+            }
+        }
+        $crate::__match_fields!($failures, $value, $path, { $($($rest)*)? });
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_elements {
+    ($failures:ident, $elements:ident, $path:ident, $index:ident, []) => {};
+    ($failures:ident, $elements:ident, $path:ident, $index:ident, [ { $($inner:tt)* } $(, $($rest:tt)*)? ]) => {
+        match $elements.get($index) {
+            Some(element) => {
+                $crate::__match_fields!($failures, element, &format!("{}[{}].", $path, $index), { $($inner)* });
+            }
+            None => $failures.push(format!("{}[{}]: Should exist, got {}", $path, $index, $crate::fields::elements($elements.len()))),
+        }
+        $index += 1;
+        $crate::__match_elements!($failures, $elements, $path, $index, [ $($($rest)*)? ]);
+    };
+    ($failures:ident, $elements:ident, $path:ident, $index:ident, [ $expected:expr $(, $($rest:tt)*)? ]) => {
+        match $elements.get($index) {
+            Some(element) => {
+                #[allow(unused_imports)]
+                use $crate::fields::{MatchWithEq as _, MatchWithMatcher as _};
+                if let Err(message) = (&&$crate::fields::Field::new(&$expected, element)).check_field() {
+                    $failures.push(format!("{}[{}]: {message}", $path, $index));
+                }
+            }
+            None => $failures.push(format!("{}[{}]: Should exist, got {}", $path, $index, $crate::fields::elements($elements.len()))),
+        }
+        $index += 1;
+        $crate::__match_elements!($failures, $elements, $path, $index, [ $($($rest)*)? ]);
+    };
+}
@@ -1,11 +1,19 @@
-use std::{any::Any, fmt::{Display, Debug}};
+use std::{any::Any, fmt::Debug};
 
-pub fn format_value(value: &dyn Any) -> String {
-    if let Some(v) = value.downcast_ref::<&dyn Display>() {
-        format!("{}", v)
-    } else if let Some(v) = value.downcast_ref::<&dyn Debug>() {
-        format!("{:?}", v)
+/// Render an expected value for a failure message. Only `Debug` is needed,
+/// so borrowed values can be rendered as well.
+pub fn format_value<V: Debug + ?Sized>(value: &V) -> String {
+    format!("{value:?}")
+}
+
+/// Describe a type-erased value as well as possible. Only string payloads,
+/// such as the ones produced by `panic!`, can be rendered.
+pub fn describe_any(value: &dyn Any) -> String {
+    if let Some(v) = value.downcast_ref::<&str>() {
+        format!("`&str` ({v:?})")
+    } else if let Some(v) = value.downcast_ref::<String>() {
+        format!("`String` ({v:?})")
     } else {
-        "<type does not implement Display or Debug>".to_string()
+        "a value of another type".to_string()
     }
-}
\ No newline at end of file
+}
@@ -1 +1,2 @@
-pub use crate::core::{Directive, Chain, CheckState, CheckMateEntrypoint, Assertions};
\ No newline at end of file
+pub use crate::core::{Directive, Chain, CheckState, CheckMateEntrypoint, Assertions};
+pub use crate::future::FutureEntrypoint;
\ No newline at end of file
@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash};
+use std::marker::PhantomData;
+
+use super::{Generator, Rng};
+
+const DEFAULT_MAX_LEN: usize = 32;
+
+/// Generates any value of `T`, favouring small and edge case values.
+pub struct Any<T>(PhantomData<T>);
+
+pub fn any<T>() -> Any<T> {
+    Any(PhantomData)
+}
+
+/// Generates integers in `low..=high`, shrinking towards `low`.
+pub struct Between<T> {
+    low: T,
+    high: T,
+}
+
+pub fn between<T: PartialOrd>(low: T, high: T) -> Between<T> {
+    assert!(low <= high, "between() needs low <= high");
+    Between { low, high }
+}
+
+/// Shrink candidates for `value` moving towards `target`.
+fn shrink_towards(value: i128, target: i128) -> Vec<i128> {
+    if value == target {
+        return Vec::new();
+    }
+    let mut candidates = vec![target, target + (value - target) / 2, value - (value - target).signum()];
+    candidates.dedup();
+    candidates.retain(|candidate| *candidate != value);
+    candidates
+}
+
+macro_rules! impl_integer {
+    ($($int:ty),*) => {
+        $(
+            impl Generator for Any<$int> {
+                type Value = $int;
+
+                fn generate(&self, rng: &mut Rng) -> $int {
+                    match rng.below(8) {
+                        0 => [0, 1, <$int>::MIN, <$int>::MAX][rng.below(4) as usize],
+                        1..=4 => (rng.below(201) as i128 - 100).clamp(<$int>::MIN as i128, <$int>::MAX as i128) as $int,
+                        _ => rng.next_u64() as $int,
+                    }
+                }
+
+                fn shrink(&self, value: &$int) -> Vec<$int> {
+                    shrink_towards(*value as i128, 0).into_iter().map(|v| v as $int).collect()
+                }
+            }
+
+            impl Generator for Between<$int> {
+                type Value = $int;
+
+                fn generate(&self, rng: &mut Rng) -> $int {
+                    let span = (self.high as i128 - self.low as i128) as u128 + 1;
+                    let offset = ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128) % span;
+                    (self.low as i128 + offset as i128) as $int
+                }
+
+                fn shrink(&self, value: &$int) -> Vec<$int> {
+                    shrink_towards(*value as i128, self.low as i128).into_iter().map(|v| v as $int).collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! impl_float {
+    ($($float:ty),*) => {
+        $(
+            impl Generator for Any<$float> {
+                type Value = $float;
+
+                fn generate(&self, rng: &mut Rng) -> $float {
+                    match rng.below(8) {
+                        0 => [0.0, 1.0, -1.0, <$float>::MIN, <$float>::MAX][rng.below(5) as usize],
+                        _ => (rng.next_u64() as f64 / u64::MAX as f64 * 2.0e6 - 1.0e6) as $float,
+                    }
+                }
+
+                fn shrink(&self, value: &$float) -> Vec<$float> {
+                    let mut candidates = vec![0.0, value.trunc(), value / 2.0];
+                    candidates.retain(|candidate| candidate != value && candidate.is_finite());
+                    candidates
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl Generator for Any<bool> {
+    type Value = bool;
+
+    fn generate(&self, rng: &mut Rng) -> bool {
+        rng.below(2) == 1
+    }
+
+    fn shrink(&self, value: &bool) -> Vec<bool> {
+        if *value { vec![false] } else { Vec::new() }
+    }
+}
+
+impl Generator for Any<char> {
+    type Value = char;
+
+    /// Mostly printable ASCII, sometimes any other character.
+    fn generate(&self, rng: &mut Rng) -> char {
+        if rng.below(4) == 0 {
+            loop {
+                if let Some(c) = char::from_u32(rng.below(0x11_0000) as u32) {
+                    return c;
+                }
+            }
+        }
+        char::from(b' ' + rng.below(95) as u8)
+    }
+
+    fn shrink(&self, value: &char) -> Vec<char> {
+        const SIMPLEST: [char; 4] = ['a', 'A', '0', ' '];
+        let simpler = SIMPLEST.iter().position(|c| c == value).unwrap_or(SIMPLEST.len());
+        SIMPLEST[..simpler].to_vec()
+    }
+}
+
+impl Generator for Any<String> {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        strings().generate(rng)
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        strings().shrink(value)
+    }
+}
+
+/// Shrink a sequence by dropping parts of it, then by shrinking the elements.
+fn shrink_elements<T: Clone>(values: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    if values.is_empty() {
+        return candidates;
+    }
+    candidates.push(Vec::new());
+    if values.len() > 1 {
+        candidates.push(values[..values.len() / 2].to_vec());
+        candidates.push(values[values.len() / 2..].to_vec());
+    }
+    for i in 0..values.len() {
+        let mut removed = values.to_vec();
+        removed.remove(i);
+        candidates.push(removed);
+    }
+    for (i, value) in values.iter().enumerate() {
+        if let Some(smaller) = shrink(value).into_iter().next() {
+            let mut shrunk = values.to_vec();
+            shrunk[i] = smaller;
+            candidates.push(shrunk);
+        }
+    }
+    candidates
+}
+
+pub struct VecGenerator<G> {
+    element: G,
+    max_len: usize,
+}
+
+/// Generates vectors of values produced by `element`.
+pub fn vecs<G: Generator>(element: G) -> VecGenerator<G> {
+    VecGenerator { element, max_len: DEFAULT_MAX_LEN }
+}
+
+impl<G> VecGenerator<G> {
+    pub fn max_len(self, max_len: usize) -> Self {
+        Self { max_len, ..self }
+    }
+}
+
+impl<G> Generator for VecGenerator<G>
+where
+    G: Generator,
+    G::Value: Clone,
+{
+    type Value = Vec<G::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = rng.below(self.max_len as u64 + 1) as usize;
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        shrink_elements(value, |element| self.element.shrink(element))
+    }
+}
+
+pub struct StringGenerator {
+    max_len: usize,
+}
+
+/// Generates strings of mostly printable ASCII characters.
+pub fn strings() -> StringGenerator {
+    StringGenerator { max_len: DEFAULT_MAX_LEN }
+}
+
+impl StringGenerator {
+    pub fn max_len(self, max_len: usize) -> Self {
+        Self { max_len }
+    }
+}
+
+impl Generator for StringGenerator {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        vecs(any::<char>()).max_len(self.max_len).generate(rng).into_iter().collect()
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let chars: Vec<char> = value.chars().collect();
+        shrink_elements(&chars, |c| any::<char>().shrink(c))
+            .into_iter()
+            .map(|chars| chars.into_iter().collect())
+            .collect()
+    }
+}
+
+pub struct MapGenerator<K, V> {
+    keys: K,
+    values: V,
+    max_len: usize,
+}
+
+/// Generates hash maps with keys and values produced by the given generators.
+pub fn maps<K: Generator, V: Generator>(keys: K, values: V) -> MapGenerator<K, V> {
+    MapGenerator { keys, values, max_len: DEFAULT_MAX_LEN }
+}
+
+impl<K, V> MapGenerator<K, V> {
+    pub fn max_len(self, max_len: usize) -> Self {
+        Self { max_len, ..self }
+    }
+}
+
+impl<K, V> Generator for MapGenerator<K, V>
+where
+    K: Generator,
+    K::Value: Clone + Eq + Hash,
+    V: Generator,
+    V::Value: Clone,
+{
+    type Value = HashMap<K::Value, V::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = rng.below(self.max_len as u64 + 1) as usize;
+        (0..len).map(|_| (self.keys.generate(rng), self.values.generate(rng))).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        // The iteration order of a HashMap changes from run to run. Order the
+        // entries by a fixed hash so a seed always shrinks the same way
+        let mut entries: Vec<(K::Value, V::Value)> = value.clone().into_iter().collect();
+        let hasher = BuildHasherDefault::<DefaultHasher>::default();
+        entries.sort_by_cached_key(|(key, _)| hasher.hash_one(key));
+        shrink_elements(&entries, |(key, value)| {
+            self.values.shrink(value).into_iter().map(|value| (key.clone(), value)).collect()
+        })
+        .into_iter()
+        .map(|entries| entries.into_iter().collect())
+        .collect()
+    }
+}
+
+impl<A, B> Generator for (A, B)
+where
+    A: Generator,
+    A::Value: Clone,
+    B: Generator,
+    B::Value: Clone,
+{
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (self.0.generate(rng), self.1.generate(rng))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        let mut candidates: Vec<Self::Value> = self.0.shrink(a).into_iter().map(|a| (a, b.clone())).collect();
+        candidates.extend(self.1.shrink(b).into_iter().map(|b| (a.clone(), b)));
+        candidates
+    }
+}
+
+impl<A, B, C> Generator for (A, B, C)
+where
+    A: Generator,
+    A::Value: Clone,
+    B: Generator,
+    B::Value: Clone,
+    C: Generator,
+    C::Value: Clone,
+{
+    type Value = (A::Value, B::Value, C::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (self.0.generate(rng), self.1.generate(rng), self.2.generate(rng))
+    }
+
+    fn shrink(&self, (a, b, c): &Self::Value) -> Vec<Self::Value> {
+        let mut candidates: Vec<Self::Value> =
+            self.0.shrink(a).into_iter().map(|a| (a, b.clone(), c.clone())).collect();
+        candidates.extend(self.1.shrink(b).into_iter().map(|b| (a.clone(), b, c.clone())));
+        candidates.extend(self.2.shrink(c).into_iter().map(|c| (a.clone(), b.clone(), c)));
+        candidates
+    }
+}
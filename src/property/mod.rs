@@ -0,0 +1,136 @@
+mod generators;
+
+use std::env;
+use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::{failure_message, CheckState, Checked};
+
+pub use generators::{any, between, maps, strings, vecs, Any, Between, MapGenerator, StringGenerator, VecGenerator};
+
+pub const SEED_ENV_VAR: &str = "CHECKMATE_SEED";
+
+/// Upper bound on the number of candidates tried while shrinking a failure.
+const MAX_SHRINK_ATTEMPTS: usize = 10_000;
+
+/// Small deterministic random number generator (SplitMix64), so a failing
+/// run can be reproduced from its seed.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A random number in `0..bound`, or 0 if `bound` is 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            0
+        } else {
+            self.next_u64() % bound
+        }
+    }
+}
+
+pub trait Generator {
+    type Value;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Simpler candidates for a failing value, most promising first.
+    fn shrink(&self, _value: &Self::Value) -> Vec<Self::Value> {
+        Vec::new()
+    }
+}
+
+/// Run a check against many values produced by `generator`.
+pub fn for_all<G: Generator>(generator: G) -> ForAll<G> {
+    ForAll { generator, cases: 100, seed: None }
+}
+
+pub struct ForAll<G> {
+    generator: G,
+    cases: usize,
+    seed: Option<u64>,
+}
+
+fn default_seed() -> u64 {
+    if let Some(seed) = env::var(SEED_ENV_VAR).ok().and_then(|seed| seed.parse().ok()) {
+        return seed;
+    }
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+impl<G> ForAll<G>
+where
+    G: Generator,
+    G::Value: Clone + Debug,
+{
+    pub fn cases(self, cases: usize) -> Self {
+        Self { cases, ..self }
+    }
+
+    /// Use a fixed seed. Without it the seed is read from `CHECKMATE_SEED`
+    /// or derived from the clock.
+    pub fn seed(self, seed: u64) -> Self {
+        Self { seed: Some(seed), ..self }
+    }
+
+    /// Check every generated value. The first failure is shrunk to a minimal
+    /// counterexample, which is reported with the seed to reproduce it.
+    pub fn check<C, R: CheckState<C>>(self, check: impl Fn(G::Value) -> R) -> Checked<()> {
+        let seed = self.seed.unwrap_or_else(default_seed);
+        let mut rng = Rng::new(seed);
+
+        for case in 1..=self.cases {
+            let value = self.generator.generate(&mut rng);
+            let Some(message) = failure_message(&(check)(value.clone())) else {
+                continue;
+            };
+            let (value, message, shrinks) = self.shrink(value, message, &check);
+            return Checked::invalid((), format!(
+                "Property failed for {value:?} (case {case}, shrunk {shrinks} times, rerun with {SEED_ENV_VAR}={seed}): {message}"
+            ));
+        }
+        Checked::valid(())
+    }
+
+    fn shrink<C, R: CheckState<C>>(
+        &self,
+        mut value: G::Value,
+        mut message: String,
+        check: &impl Fn(G::Value) -> R,
+    ) -> (G::Value, String, usize) {
+        let mut shrinks = 0;
+        let mut attempts = 0;
+        'shrinking: while attempts < MAX_SHRINK_ATTEMPTS {
+            for candidate in self.generator.shrink(&value) {
+                attempts += 1;
+                if let Some(candidate_message) = failure_message(&(check)(candidate.clone())) {
+                    value = candidate;
+                    message = candidate_message;
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+                if attempts >= MAX_SHRINK_ATTEMPTS {
+                    break;
+                }
+            }
+            break;
+        }
+        (value, message, shrinks)
+    }
+}
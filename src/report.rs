@@ -0,0 +1,196 @@
+use std::cell::Cell;
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Path of the report file. Reports are written as JUnit XML when the path
+/// ends in `.xml` and as JSON lines otherwise. `{binary}` in the path is
+/// replaced by the name of the test binary. The report of a previous run is
+/// replaced.
+pub const REPORT_ENV_VAR: &str = "CHECKMATE_REPORT";
+
+/// The outcome of one assertion on a `Checked`.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub test: String,
+    pub location: String,
+    pub passed: bool,
+    pub message: Option<String>,
+    pub elapsed: Duration,
+}
+
+enum Output {
+    /// The file is created once per process and the records are appended
+    /// as the assertions run. It is missing if it could not be created.
+    JsonLines(Option<File>),
+    /// JUnit reports are a single document, so it is rewritten with all
+    /// records of this process after each assertion.
+    JUnit {
+        path: PathBuf,
+        records: Vec<Record>,
+    },
+}
+
+static REPORTER: OnceLock<Option<Mutex<Output>>> = OnceLock::new();
+
+thread_local! {
+    static LAST_MARK: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+fn binary_name() -> String {
+    env::current_exe()
+        .ok()
+        .and_then(|exe| exe.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+        .map(|stem| match stem.rsplit_once('-') {
+            // Strip the hash cargo appends to test binaries
+            Some((name, hash)) if hash.chars().all(|c| c.is_ascii_hexdigit()) => name.to_string(),
+            _ => stem,
+        })
+        .unwrap_or_else(|| "checkmate".to_string())
+}
+
+fn reporter() -> Option<&'static Mutex<Output>> {
+    REPORTER
+        .get_or_init(|| {
+            let path = env::var(REPORT_ENV_VAR).ok().filter(|path| !path.is_empty())?;
+            let path = PathBuf::from(path.replace("{binary}", &binary_name()));
+            let output = match path.extension() {
+                Some(extension) if extension == "xml" => Output::JUnit { path, records: Vec::new() },
+                _ => Output::JsonLines(create_parent(&path).and_then(|()| File::create(&path)).ok()),
+            };
+            Some(Mutex::new(output))
+        })
+        .as_ref()
+}
+
+pub fn enabled() -> bool {
+    reporter().is_some()
+}
+
+/// Start timing the checks of the current thread, unless a previous check
+/// already did. Called by the entrypoints when reporting is enabled.
+pub(crate) fn mark_start() {
+    if enabled() {
+        LAST_MARK.with(|mark| {
+            if mark.get().is_none() {
+                mark.set(Some(Instant::now()));
+            }
+        });
+    }
+}
+
+/// Record the outcome of an assertion. The elapsed time runs from the
+/// previous recorded assertion, or the first entrypoint call, on this thread.
+pub(crate) fn record(location: &Location, passed: bool, message: Option<&str>) {
+    let Some(reporter) = reporter() else {
+        return;
+    };
+    let now = Instant::now();
+    let elapsed = LAST_MARK.with(|mark| mark.replace(Some(now))).map_or(Duration::ZERO, |start| now - start);
+    let record = Record {
+        test: thread::current().name().unwrap_or("main").to_string(),
+        location: format!("{}:{}:{}", location.file(), location.line(), location.column()),
+        passed,
+        message: message.map(str::to_string),
+        elapsed,
+    };
+
+    let mut output = reporter.lock().unwrap_or_else(|err| err.into_inner());
+    match &mut *output {
+        // Reporting must never fail a test, so write errors are ignored
+        Output::JsonLines(Some(file)) => {
+            let _ = file.write_all(format!("{}\n", to_json_line(&record)).as_bytes());
+        }
+        Output::JsonLines(None) => (),
+        Output::JUnit { path, records } => {
+            records.push(record);
+            let _ = write_junit(path, records);
+        }
+    }
+}
+
+fn create_parent(path: &Path) -> std::io::Result<()> {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => fs::create_dir_all(parent),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Render a record as a single line JSON object.
+pub fn to_json_line(record: &Record) -> String {
+    let message = match &record.message {
+        Some(message) => format!("\"{}\"", escape_json(message)),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"test\":\"{}\",\"location\":\"{}\",\"passed\":{},\"message\":{},\"elapsed_secs\":{}}}",
+        escape_json(&record.test),
+        escape_json(&record.location),
+        record.passed,
+        message,
+        record.elapsed.as_secs_f64(),
+    )
+}
+
+/// Render records as a JUnit XML document with one test case per record.
+pub fn to_junit(suite: &str, records: &[Record]) -> String {
+    let failures = records.iter().filter(|record| !record.passed).count();
+    let time: f64 = records.iter().map(|record| record.elapsed.as_secs_f64()).sum();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+    xml.push_str(&format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{failures}\" time=\"{time}\">\n",
+        escape_xml(suite),
+        records.len(),
+    ));
+    for record in records {
+        xml.push_str(&format!(
+            "    <testcase classname=\"{}\" name=\"{}\" time=\"{}\"",
+            escape_xml(&record.test),
+            escape_xml(&record.location),
+            record.elapsed.as_secs_f64(),
+        ));
+        if record.passed {
+            xml.push_str("/>\n");
+        } else {
+            let message = escape_xml(record.message.as_deref().unwrap_or(""));
+            xml.push_str(&format!(">\n      <failure message=\"{message}\">{message}</failure>\n    </testcase>\n"));
+        }
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+fn write_junit(path: &Path, records: &[Record]) -> std::io::Result<()> {
+    create_parent(path)?;
+    File::create(path)?.write_all(to_junit(&binary_name(), records).as_bytes())
+}
@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::diff::line_diff;
+
+pub const UPDATE_ENV_VAR: &str = "CHECKMATE_UPDATE_SNAPSHOTS";
+
+/// Snapshots are rewritten instead of compared when the update variable is
+/// set to anything but `0`.
+pub fn update_mode() -> bool {
+    env::var(UPDATE_ENV_VAR).map(|value| value != "0").unwrap_or(false)
+}
+
+/// Source paths reported by `Location::file` are relative to the workspace
+/// root, while tests run in the directory of their package. Walk up from the
+/// package to the closest manifest declaring a workspace.
+fn workspace_root() -> PathBuf {
+    let Some(package) = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from) else {
+        return PathBuf::new();
+    };
+    package
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .map(|manifest| manifest.lines().any(|line| line.trim_start().starts_with("[workspace")))
+                .unwrap_or(false)
+        })
+        .unwrap_or(&package)
+        .to_path_buf()
+}
+
+/// Snapshots are stored in a `snapshots` directory next to the file that
+/// calls the check, named after that file.
+fn snapshot_file(source: &Path, suffix: &str) -> PathBuf {
+    let stem = source.file_stem().and_then(|stem| stem.to_str()).unwrap_or("snapshot");
+    source
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join("snapshots")
+        .join(format!("{stem}{suffix}"))
+}
+
+fn snapshot_path(location: &Location, name: &str) -> PathBuf {
+    snapshot_file(Path::new(location.file()), &format!("__{name}.snap"))
+}
+
+/// Pending inline snapshot updates for a source file, to be reviewed and then
+/// applied with `apply_inline_snapshots`.
+pub fn pending_inline_path(source: impl AsRef<Path>) -> PathBuf {
+    snapshot_file(source.as_ref(), ".pending-inline")
+}
+
+fn normalize(content: &str) -> String {
+    content.replace("\r\n", "\n").trim_end_matches('\n').to_string()
+}
+
+/// Compare `actual` with the stored snapshot, or store it in update mode.
+/// Returns the failure message on a mismatch.
+pub fn check_file_snapshot(location: &Location, name: &str, actual: &str) -> Result<(), String> {
+    let shown = snapshot_path(location, name);
+    let path = workspace_root().join(&shown);
+    let actual = normalize(actual);
+
+    if update_mode() {
+        let written = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&path, format!("{actual}\n")));
+        return written.map_err(|err| format!("Could not write snapshot `{}`: {err}", shown.display()));
+    }
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(content) => normalize(&content),
+        Err(_) => {
+            return Err(format!(
+                "Snapshot `{}` does not exist, run with {UPDATE_ENV_VAR}=1 to create it",
+                shown.display()
+            ))
+        }
+    };
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(format!(
+            "Should match snapshot `{}`:\n{}",
+            shown.display(),
+            line_diff(&expected, &actual)
+        ))
+    }
+}
+
+/// Remove a leading line break and the indentation shared by all lines, so
+/// inline snapshots can be indented along with the surrounding code.
+fn dedent(content: &str) -> String {
+    let content = normalize(content);
+    let content = content.strip_prefix('\n').unwrap_or(&content).trim_end();
+    let indent = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    content
+        .lines()
+        .map(|line| line.get(indent..).unwrap_or(line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+static PENDING_LOCK: Mutex<()> = Mutex::new(());
+
+/// Compare `actual` with an inline snapshot. In update mode a mismatch is
+/// recorded in the pending file of the calling source file instead.
+pub fn check_inline_snapshot(location: &Location, expected: &str, actual: &str) -> Result<(), String> {
+    let expected = dedent(expected);
+    let actual = dedent(actual);
+    if expected == actual {
+        return Ok(());
+    }
+
+    if update_mode() {
+        let shown = pending_inline_path(location.file());
+        let path = workspace_root().join(&shown);
+        let _guard = PENDING_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let entry = format!(
+            "@@ {}:{} {}\n{actual}\n",
+            location.line(),
+            location.column(),
+            actual.lines().count()
+        );
+        let written = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| OpenOptions::new().create(true).append(true).open(&path))
+            .and_then(|mut file| file.write_all(entry.as_bytes()));
+        return written.map_err(|err| format!("Could not write pending snapshot `{}`: {err}", shown.display()));
+    }
+
+    Err(format!(
+        "Should match inline snapshot at {}:{}:\n{}",
+        location.file(),
+        location.line(),
+        line_diff(&expected, &actual)
+    ))
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Parse pending entries, keyed by line and column. A later entry for the
+/// same location replaces an earlier one.
+fn parse_pending(pending: &str) -> io::Result<BTreeMap<(usize, usize), String>> {
+    let mut entries = BTreeMap::new();
+    let mut lines = pending.lines();
+    while let Some(header) = lines.next() {
+        let parsed = header.strip_prefix("@@ ").and_then(|header| {
+            let (position, count) = header.split_once(' ')?;
+            let (line, column) = position.split_once(':')?;
+            Some((line.parse().ok()?, column.parse().ok()?, count.parse::<usize>().ok()?))
+        });
+        let Some((line, column, count)) = parsed else {
+            return Err(invalid_data(format!("Invalid pending snapshot header {header:?}")));
+        };
+        let content: Vec<&str> = lines.by_ref().take(count).collect();
+        if content.len() != count {
+            return Err(invalid_data(format!("Pending snapshot at {line}:{column} is truncated")));
+        }
+        entries.insert((line, column), content.join("\n"));
+    }
+    Ok(entries)
+}
+
+/// Byte offset of a 1-based line and column in `source`.
+fn offset_of(source: &str, line: usize, column: usize) -> Option<usize> {
+    let mut start = 0;
+    for _ in 1..line {
+        start += source[start..].find('\n')? + 1;
+    }
+    let line = source[start..].split('\n').next()?;
+    let in_line = line.char_indices().nth(column.checked_sub(1)?).map(|(i, _)| i)?;
+    Some(start + in_line)
+}
+
+/// Byte range of the first string literal argument after `offset`, which
+/// points at the name of the called method.
+fn literal_range(source: &str, offset: usize) -> Option<(usize, usize)> {
+    let open = offset + source[offset..].find('(')? + 1;
+    let start = open + (source[open..].len() - source[open..].trim_start().len());
+    let literal = &source[start..];
+
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = raw[hashes..].strip_prefix('"')?;
+        let terminator = format!("\"{}", "#".repeat(hashes));
+        let end = body.find(&terminator)? + terminator.len();
+        return Some((start, start + 1 + hashes + 1 + end));
+    }
+
+    let body = literal.strip_prefix('"')?;
+    let mut escaped = false;
+    for (i, c) in body.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some((start, start + 1 + i + 1)),
+            _ => (),
+        }
+    }
+    None
+}
+
+fn render_literal(content: &str) -> String {
+    let mut hashes = 1;
+    while content.contains(&format!("\"{}", "#".repeat(hashes))) {
+        hashes += 1;
+    }
+    let hashes = "#".repeat(hashes);
+    if content.contains('\n') {
+        format!("r{hashes}\"\n{content}\n\"{hashes}")
+    } else {
+        format!("r{hashes}\"{content}\"{hashes}")
+    }
+}
+
+/// Rewrite the inline snapshot literals in `source` with the pending updates
+/// recorded for it, then remove the pending file. Returns the number of
+/// rewritten snapshots.
+pub fn apply_inline_snapshots(source: impl AsRef<Path>) -> io::Result<usize> {
+    let source = source.as_ref();
+    let pending_path = pending_inline_path(source);
+    let entries = match fs::read_to_string(&pending_path) {
+        Ok(pending) => parse_pending(&pending)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err),
+    };
+
+    let mut code = fs::read_to_string(source)?;
+    // Rewrite from the end of the file so earlier offsets stay valid
+    for ((line, column), content) in entries.iter().rev() {
+        let range = offset_of(&code, *line, *column).and_then(|offset| literal_range(&code, offset));
+        let Some((start, end)) = range else {
+            return Err(invalid_data(format!("No inline snapshot literal at {}:{line}:{column}", source.display())));
+        };
+        code.replace_range(start..end, &render_literal(content));
+    }
+    fs::write(source, code)?;
+    fs::remove_file(pending_path)?;
+    Ok(entries.len())
+}
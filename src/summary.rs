@@ -0,0 +1,232 @@
+use std::io::IsTerminal;
+
+use crate::core::{CheckState, Checked, CheckedChild, Described};
+
+/// A check result that can be added to a `Summary`.
+pub trait Outcome {
+    /// The failure message, if any, with the path of the nested value that
+    /// failed. The path holds the names given with `describe` and `context`.
+    fn failure(&self) -> Option<(Vec<String>, String)>;
+}
+
+fn state_failure<T>(state: &impl CheckState<T>) -> Option<(Vec<String>, String)> {
+    let message = state.get_state_as_ref().message()?;
+    Some((state.trail().to_vec(), message.to_string()))
+}
+
+impl<T> Outcome for Checked<T> {
+    fn failure(&self) -> Option<(Vec<String>, String)> {
+        state_failure(self)
+    }
+}
+
+impl<T> Outcome for Described<T> {
+    fn failure(&self) -> Option<(Vec<String>, String)> {
+        state_failure(self)
+    }
+}
+
+impl<P, C> Outcome for CheckedChild<P, C> {
+    fn failure(&self) -> Option<(Vec<String>, String)> {
+        if let Some(message) = self.parent_message() {
+            return Some((self.parent_trail().to_vec(), message.to_string()));
+        }
+        state_failure(self)
+    }
+}
+
+enum Node {
+    Check {
+        name: String,
+        failure: Option<(Vec<String>, String)>,
+    },
+    Scope {
+        name: String,
+        summary: Summary,
+    },
+}
+
+/// Collects check results outside of the cargo test harness and renders
+/// them as TAP or as a short human readable summary.
+#[derive(Default)]
+pub struct Summary {
+    nodes: Vec<Node>,
+}
+
+struct Failure<'a> {
+    scope: Vec<&'a str>,
+    name: &'a str,
+    path: &'a [String],
+    message: &'a str,
+}
+
+impl Summary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the result of a named check.
+    pub fn check(&mut self, name: &str, outcome: impl Outcome) -> &mut Self {
+        self.nodes.push(Node::Check { name: name.to_string(), failure: outcome.failure() });
+        self
+    }
+
+    /// Group the checks added by `checks` under a named scope. Scopes can be
+    /// nested.
+    pub fn scope(&mut self, name: &str, checks: impl FnOnce(&mut Summary)) -> &mut Self {
+        let mut summary = Summary::new();
+        checks(&mut summary);
+        self.nodes.push(Node::Scope { name: name.to_string(), summary });
+        self
+    }
+
+    /// The number of checks, including those in nested scopes.
+    pub fn total(&self) -> usize {
+        self.nodes
+            .iter()
+            .map(|node| match node {
+                Node::Check { name: _, failure: _ } => 1,
+                Node::Scope { name: _, summary } => summary.total(),
+            })
+            .sum()
+    }
+
+    /// The number of failed checks, including those in nested scopes.
+    pub fn failed(&self) -> usize {
+        self.nodes
+            .iter()
+            .map(|node| match node {
+                Node::Check { name: _, failure } => failure.is_some() as usize,
+                Node::Scope { name: _, summary } => summary.failed(),
+            })
+            .sum()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.failed() == 0
+    }
+
+    /// Render the checks as a TAP version 14 document. Scopes become
+    /// subtests and failures carry their message and path as YAML
+    /// diagnostics.
+    pub fn to_tap(&self) -> String {
+        let mut tap = String::from("TAP version 14\n");
+        self.write_tap(&mut tap, 0);
+        tap
+    }
+
+    fn write_tap(&self, tap: &mut String, depth: usize) {
+        let indent = "    ".repeat(depth);
+        tap.push_str(&format!("{indent}1..{}\n", self.nodes.len()));
+        for (index, node) in self.nodes.iter().enumerate() {
+            let number = index + 1;
+            match node {
+                Node::Check { name, failure: None } => {
+                    tap.push_str(&format!("{indent}ok {number} - {}\n", escape_tap(name)));
+                }
+                Node::Check { name, failure: Some((path, message)) } => {
+                    tap.push_str(&format!("{indent}not ok {number} - {}\n", escape_tap(name)));
+                    tap.push_str(&format!("{indent}  ---\n"));
+                    tap.push_str(&format!("{indent}  message: {}\n", yaml_string(message)));
+                    if !path.is_empty() {
+                        tap.push_str(&format!("{indent}  path: {}\n", yaml_string(&path.join(" > "))));
+                    }
+                    tap.push_str(&format!("{indent}  ...\n"));
+                }
+                Node::Scope { name, summary } => {
+                    tap.push_str(&format!("{indent}# Subtest: {}\n", escape_tap(name)));
+                    summary.write_tap(tap, depth + 1);
+                    let status = if summary.is_valid() { "ok" } else { "not ok" };
+                    tap.push_str(&format!("{indent}{status} {number} - {}\n", escape_tap(name)));
+                }
+            }
+        }
+    }
+
+    /// Render a compact summary, e.g. "42 checks, 3 failed", followed by the
+    /// failures grouped by scope. With `colour` the output uses ANSI colours.
+    pub fn to_summary(&self, colour: bool) -> String {
+        let paint = |code: &str, text: &str| {
+            if colour {
+                format!("\x1b[{code}m{text}\x1b[0m")
+            } else {
+                text.to_string()
+            }
+        };
+
+        let total = self.total();
+        let failed = self.failed();
+        let checks = if total == 1 { "check" } else { "checks" };
+        let mut output = if failed == 0 {
+            paint("32", &format!("{total} {checks}, all passed"))
+        } else {
+            paint("31", &format!("{total} {checks}, {failed} failed"))
+        };
+        output.push('\n');
+
+        let mut failures = Vec::new();
+        self.collect_failures(&mut Vec::new(), &mut failures);
+        let mut current_scope = None;
+        for failure in &failures {
+            if current_scope != Some(&failure.scope) {
+                let scope = if failure.scope.is_empty() { "(root)".to_string() } else { failure.scope.join(" > ") };
+                output.push_str(&format!("\n{}\n", paint("1", &scope)));
+                current_scope = Some(&failure.scope);
+            }
+            let mut location = vec![failure.name.to_string()];
+            location.extend(failure.path.iter().cloned());
+            output.push_str(&format!("  {} {}: ", paint("31", "✗"), location.join(" > ")));
+            output.push_str(&failure.message.replace('\n', "\n    "));
+            output.push('\n');
+        }
+        output
+    }
+
+    fn collect_failures<'a>(&'a self, scope: &mut Vec<&'a str>, failures: &mut Vec<Failure<'a>>) {
+        // Checks of a scope are listed before those of its nested scopes, so
+        // failures of the same scope end up next to each other
+        for node in &self.nodes {
+            if let Node::Check { name, failure: Some((path, message)) } = node {
+                failures.push(Failure { scope: scope.clone(), name, path, message });
+            }
+        }
+        for node in &self.nodes {
+            if let Node::Scope { name, summary } = node {
+                scope.push(name);
+                summary.collect_failures(scope, failures);
+                scope.pop();
+            }
+        }
+    }
+
+    /// Print the TAP document to stdout.
+    pub fn print_tap(&self) {
+        print!("{}", self.to_tap());
+    }
+
+    /// Print the summary to stdout, in colour when stdout is a terminal and
+    /// `NO_COLOR` is not set.
+    pub fn print_summary(&self) {
+        let colour = std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+        print!("{}", self.to_summary(colour));
+    }
+
+    /// Combine all checks into one result, for use in a test or to fail a
+    /// harness.
+    pub fn into_checked(self) -> Checked<()> {
+        if self.is_valid() {
+            Checked::valid(())
+        } else {
+            Checked::invalid((), self.to_summary(false).trim_end().to_string())
+        }
+    }
+}
+
+/// `#` starts a directive in a TAP description.
+fn escape_tap(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('#', "\\#").replace('\n', " ")
+}
+
+fn yaml_string(value: &str) -> String {
+    format!("\"{}\"", crate::report::escape_json(value))
+}
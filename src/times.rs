@@ -1,15 +1,4 @@
-use crate::core::{Times, Checked, CheckState};
-
-fn count_valid_checks<T, S: CheckState<T>>(checks: impl Iterator<Item = S>) -> usize {
-    let mut count = 0;
-    for check in checks {
-        match check.get_state_as_ref() {
-            Checked::Valid { value: _ } => count += 1,
-            Checked::Invalid { value: _, message: _ } => continue,
-        }
-    };
-    return count;
-}
+use crate::core::{Times, Checked};
 
 pub struct AtLeast(usize);
 
@@ -23,19 +12,16 @@ impl AtLeast {
     }
 
     pub fn times(times: usize) -> Self {
-        Self { 0: times }
+        Self(times)
     }
 }
 
-impl<T> Times<T> for AtLeast
-where
-    T: IntoIterator
-{
-    fn check<S: CheckState<T::Item>>(self, inner: T, message: String, checks: impl Iterator<Item = S>) -> Checked<T> {
-        if count_valid_checks(checks) >= self.0 {
+impl<T> Times<T> for AtLeast {
+    fn check_count(self, inner: T, message: impl FnOnce() -> String, count: usize) -> Checked<T> {
+        if count >= self.0 {
             Checked::valid(inner)
         } else {
-            Checked::invalid(inner, format!("{message} at least {} times", self.0))
+            Checked::invalid(inner, format!("{} at least {} times", message(), self.0))
         }
     }
 }
@@ -52,19 +38,16 @@ impl AtMost {
     }
 
     pub fn times(times: usize) -> Self {
-        Self { 0: times }
+        Self(times)
     }
 }
 
-impl<T> Times<T> for AtMost
-where
-    T: IntoIterator
-{
-    fn check<S: CheckState<T::Item>>(self, inner: T, message: String, checks: impl Iterator<Item = S>) -> Checked<T> {
-        if count_valid_checks(checks) <= self.0 {
+impl<T> Times<T> for AtMost {
+    fn check_count(self, inner: T, message: impl FnOnce() -> String, count: usize) -> Checked<T> {
+        if count <= self.0 {
             Checked::valid(inner)
         } else {
-            Checked::invalid(inner, format!("{message} at most {} times", self.0))
+            Checked::invalid(inner, format!("{} at most {} times", message(), self.0))
         }
     }
 }
@@ -81,19 +64,16 @@ impl Exactly {
     }
 
     pub fn times(times: usize) -> Self {
-        Self { 0: times }
+        Self(times)
     }
 }
 
-impl<T> Times<T> for Exactly
-where
-    T: IntoIterator
-{
-    fn check<S: CheckState<T::Item>>(self, inner: T, message: String, checks: impl Iterator<Item = S>) -> Checked<T> {
-        if count_valid_checks(checks) >= self.0 {
+impl<T> Times<T> for Exactly {
+    fn check_count(self, inner: T, message: impl FnOnce() -> String, count: usize) -> Checked<T> {
+        if count >= self.0 {
             Checked::valid(inner)
         } else {
-            Checked::invalid(inner, format!("{message} exactly {} times", self.0))
+            Checked::invalid(inner, format!("{} exactly {} times", message(), self.0))
         }
     }
 }
\ No newline at end of file
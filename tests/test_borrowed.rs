@@ -0,0 +1,130 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{self, ErrorKind};
+
+use checkmate::prelude::*;
+
+struct Fixture {
+    name: String,
+    tags: Vec<String>,
+    scores: HashMap<&'static str, u32>,
+    nickname: Option<String>,
+    parsed: Result<u32, String>,
+}
+
+fn fixture() -> Fixture {
+    Fixture {
+        name: String::from("checkmate"),
+        tags: vec![String::from("rust"), String::from("testing")],
+        scores: HashMap::from([("a", 1), ("b", 2)]),
+        nickname: None,
+        parsed: Ok(42),
+    }
+}
+
+#[test]
+fn check_fixture_repeatedly() {
+    let fixture = fixture();
+
+    for _ in 0..2 {
+        fixture.name.value_as_ref().should().start_with("check").and().have_length(9).assert_valid();
+        fixture.tags.value_as_ref().should().have_count(2).and().contain_all_of(["rust"]).assert_valid();
+        fixture.scores.value_as_ref().should().contain_key("b").and().contain_pair("a", 1).assert_valid();
+        fixture.nickname.value_as_ref().should().be_none().assert_valid();
+        fixture.parsed.value_as_ref().should().be_ok_with(42).assert_valid();
+    }
+}
+
+#[test]
+fn borrowed_string_err() {
+    let fixture = fixture();
+
+    fixture
+        .name
+        .value_as_ref()
+        .should()
+        .end_with("x")
+        .assert_invalid_with_message("Should end with \"x\"");
+}
+
+#[test]
+fn borrowed_collection_err() {
+    let fixture = fixture();
+
+    fixture
+        .tags
+        .value_as_ref()
+        .should()
+        .contain_none_of(["testing"])
+        .assert_invalid_with_message("Iterator should contain none of [\"testing\"]");
+}
+
+#[test]
+fn borrowed_str_and_slice() {
+    "checkmate".value().should().contain_substring("mate").assert_valid();
+    (&[1, 2, 3][..]).value().should().not_be_empty().and().contain_any_of([3]).assert_valid();
+}
+
+#[test]
+fn borrowed_option_child() {
+    let nickname = Some(String::from("cm"));
+
+    nickname
+        .value_as_ref()
+        .should()
+        .be_some_and()
+        .should()
+        .have_length(3)
+        .done()
+        .assert_invalid_with_message("Lenght should be 3");
+}
+
+#[test]
+fn borrowed_result_child() {
+    let parsed: Result<u32, String> = Err(String::from("bad input"));
+
+    parsed
+        .value_as_ref()
+        .should()
+        .be_err_and()
+        .should()
+        .contain_substring("bad")
+        .done()
+        .assert_valid();
+    parsed
+        .value_as_ref()
+        .should()
+        .be_ok_and()
+        .done()
+        .assert_invalid_with_message("Should be Ok, got Err(\"bad input\")");
+}
+
+#[test]
+fn borrowed_bool_and_io() {
+    let flag = true;
+    let err = io::Error::new(ErrorKind::NotFound, "missing");
+
+    flag.value_as_ref().should().be_true("Should be set").assert_valid();
+    err.value_as_ref().should().have_kind(ErrorKind::NotFound).assert_valid();
+}
+
+#[test]
+fn borrowed_downcast() {
+    let err: Box<dyn Error> = Box::new(io::Error::new(ErrorKind::NotFound, "missing"));
+    let value: Box<dyn Any> = Box::new(10_u8);
+
+    err.value_as_ref()
+        .should()
+        .be_of_type::<io::Error>()
+        .should()
+        .have_kind(ErrorKind::NotFound)
+        .done()
+        .assert_valid();
+    value
+        .value_as_ref()
+        .should()
+        .be_of_type::<u16>()
+        .done()
+        .assert_invalid_with_message("Should be of type `u16`, got a value of another type");
+}
@@ -0,0 +1,88 @@
+use checkmate::prelude::*;
+
+#[test]
+fn be_bytes() {
+    vec![0xde, 0xad, 0xbe, 0xef].value().should().be_bytes([0xde, 0xad, 0xbe, 0xef]).assert_valid();
+}
+
+#[test]
+fn be_bytes_err() {
+    let actual: Vec<u8> = (0..12).collect();
+    let mut expected = actual.clone();
+    expected[2] = 0xff;
+    expected[9] = 0xaa;
+
+    actual.value().should().be_bytes(&expected).assert_invalid_with_message(
+        "Should be the expected bytes (12 bytes, got 12), 2 bytes differ starting at offset 0x2
+offset    expected                actual
+00000000  00 01 ff 03 04 05 06 07 00 01 02 03 04 05 06 07
+                ^^                      ^^
+00000008  08 aa 0a 0b             08 09 0a 0b
+             ^^                      ^^",
+    );
+}
+
+#[test]
+fn be_bytes_length_err() {
+    b"abc".value().should().be_bytes(b"ab").assert_invalid_with_message(
+        "Should be the expected bytes (2 bytes, got 3), 1 byte differs starting at offset 0x2
+offset    expected                actual
+00000000  61 62 --                61 62 63
+                ^^                      ^^",
+    );
+}
+
+#[test]
+fn start_with_bytes() {
+    let frame: &[u8] = &[0x02, 0x10, 0x00, 0x03];
+
+    frame.value().should().start_with_bytes([0x02, 0x10]).assert_valid();
+    frame.value().should().start_with_bytes([0x02, 0x11]).assert_invalid_with_message(
+        "Should start with the expected bytes (2 bytes, got 2), 1 byte differs starting at offset 0x1
+offset    expected                actual
+00000000  02 11                   02 10
+             ^^                      ^^",
+    );
+}
+
+#[test]
+fn have_byte_at() {
+    let frame = [0x02, 0x10, 0x00];
+
+    frame.value().should().have_byte_at(1, 0x10).assert_valid();
+    frame
+        .value()
+        .should()
+        .have_byte_at(2, 0x03)
+        .assert_invalid_with_message("Should have byte 0x03 at offset 0x2, got 0x00");
+    frame
+        .value()
+        .should()
+        .have_byte_at(3, 0x03)
+        .assert_invalid_with_message("Should have byte 0x03 at offset 0x3, but buffer has only 3 bytes");
+}
+
+#[test]
+fn be_utf8() {
+    b"hello".value().should().be_utf8().assert_valid();
+    vec![b'o', b'k', 0xff]
+        .value()
+        .should()
+        .be_utf8()
+        .assert_invalid_with_message("Should be valid UTF-8, invalid sequence at offset 0x2");
+}
+
+#[test]
+fn decode_utf8() {
+    b"hello world"
+        .to_vec()
+        .value()
+        .should()
+        .decode_utf8()
+        .should()
+        .start_with("hello")
+        .done()
+        .should()
+        .have_len(11)
+        .assert_valid();
+}
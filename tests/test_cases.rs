@@ -0,0 +1,28 @@
+use checkmate::cases;
+use checkmate::prelude::*;
+
+fn word_count(input: &str) -> usize {
+    input.split(' ').filter(|word| !word.is_empty()).count()
+}
+
+#[test]
+fn all_cases_valid() {
+    cases([("", 0), ("one", 1), ("one two", 2), ("  spaced   out ", 2)])
+        .check(|(input, expected)| word_count(input).value().should().be(expected))
+        .assert_valid();
+}
+
+#[test]
+fn report_failing_rows() {
+    cases([("", 0), ("one", 2), ("one two", 2), ("a b c", 4)])
+        .check(|(input, expected)| word_count(input).value().should().be(expected))
+        .assert_invalid_with_message("2 of 4 cases failed:\nrow 1: Should be '2'\nrow 3: Should be '4'");
+}
+
+#[test]
+fn report_labelled_rows() {
+    cases([("", 1), ("one", 1)])
+        .label(|(input, _)| format!("input {input:?}"))
+        .check(|(input, expected)| word_count(input).value().should().be(expected))
+        .assert_invalid_with_message("1 of 2 cases failed:\nrow 0 (input \"\"): Should be '1'");
+}
@@ -17,6 +17,15 @@ impl IntoIterator for MyStruct {
     }
 }
 
+impl<'a> IntoIterator for &'a MyStruct {
+    type Item = &'a f32;
+    type IntoIter = std::slice::Iter<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coll.iter()
+    }
+}
+
 #[test]
 fn check_struct_fields_which() {
     let s = MyStruct { attr_1: 10, attr_2: String::from("hello"), coll: vec![1.2, 3.4, 5.6] };
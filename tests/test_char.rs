@@ -0,0 +1,80 @@
+use checkmate::prelude::*;
+use checkmate::CharClass;
+
+struct Vowels;
+
+impl CharClass for Vowels {
+    fn contains_char(&self, c: char) -> bool {
+        "aeiou".contains(c.to_ascii_lowercase())
+    }
+
+    fn describe(&self) -> String {
+        "vowels".to_string()
+    }
+}
+
+#[test]
+fn char_classes() {
+    'a'.value().should().be_alphabetic().and().be_in_range('a'..='z').assert_valid();
+    '7'.value().should().be_ascii_digit().assert_valid();
+    '\t'.value().should().be_whitespace().assert_valid();
+    'Q'.value().should().be_uppercase().assert_valid();
+}
+
+#[test]
+fn char_classes_err() {
+    '1'.value().should().be_alphabetic().assert_invalid_with_message("Should be alphabetic, got '1'");
+    'x'.value().should().be_ascii_digit().assert_invalid_with_message("Should be an ASCII digit, got 'x'");
+    'q'.value().should().be_uppercase().assert_invalid_with_message("Should be uppercase, got 'q'");
+    'A'.value()
+        .should()
+        .be_in_range('a'..='z')
+        .assert_invalid_with_message("Should be in range 'a'..='z', got 'A'");
+}
+
+#[test]
+fn borrowed_char() {
+    let separators = [' ', '\n'];
+
+    separators.value().should().element_at(1).should().be_whitespace().done().assert_valid();
+    (&separators[0]).value().should().be_whitespace().assert_valid();
+}
+
+#[test]
+fn consist_only_of() {
+    let is_identifier = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    "user_name_1".value().should().consist_only_of(is_identifier).assert_valid();
+    "0110".value().should().consist_only_of("01").assert_valid();
+    "abc".value().should().consist_only_of('a'..='c').assert_valid();
+    "user-name"
+        .value()
+        .should()
+        .consist_only_of(is_identifier)
+        .assert_invalid_with_message("Should consist only of the character class, got '-' at index 4");
+    "hex!"
+        .value()
+        .should()
+        .consist_only_of(['h', 'e', 'x'])
+        .assert_invalid_with_message("Should consist only of characters of ['h', 'e', 'x'], got '!' at index 3");
+}
+
+#[test]
+fn consist_only_of_custom_class() {
+    "AeiOu".value().should().consist_only_of(Vowels).assert_valid();
+    "ouch"
+        .value()
+        .should()
+        .consist_only_of(Vowels)
+        .assert_invalid_with_message("Should consist only of vowels, got 'c' at index 2");
+}
+
+#[test]
+fn contain_no_control_characters() {
+    String::from("plain text").value().should().contain_no_control_characters().assert_valid();
+    String::from("bell\u{7}")
+        .value()
+        .should()
+        .contain_no_control_characters()
+        .assert_invalid_with_message("Should contain no control characters, got '\\u{7}' at index 4");
+}
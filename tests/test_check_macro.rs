@@ -0,0 +1,58 @@
+use checkmate::check;
+use checkmate::prelude::*;
+
+#[test]
+fn check_comparisons() {
+    let v = [1, 2, 3];
+    let (a, b) = (1.5, 2.0);
+
+    check!(v.len() == 3).assert_valid();
+    check!(v[0] != v[1]).assert_valid();
+    check!(a < b).assert_valid();
+    check!(a <= 1.5).assert_valid();
+    check!(b > a).assert_valid();
+    check!(b >= 2.0).assert_valid();
+}
+
+#[test]
+fn check_comparison_err() {
+    let name = String::from("bob");
+
+    check!(name.to_uppercase() == "ALICE")
+        .assert_invalid_with_message(
+            "Check `name.to_uppercase() == \"ALICE\"` failed: left `name.to_uppercase()` is \"BOB\", right `\"ALICE\"` is \"ALICE\"",
+        );
+}
+
+#[test]
+fn check_evaluates_operands_once() {
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        calls
+    };
+
+    check!(next() < 2).assert_valid();
+    calls.value().should().be(1).assert_valid();
+}
+
+#[test]
+fn check_boolean_expression() {
+    let v: Vec<i32> = (1..=3).collect();
+
+    check!(v.contains(&2) && v.len() == 3).assert_valid();
+    check!(v.is_empty() || v[0] == 1).assert_valid();
+    check!(v.is_empty()).assert_invalid_with_message("Check `v.is_empty()` failed");
+    check!(v.iter().any(|x| *x > 5)).assert_invalid_with_message("Check `v.iter().any(|x| *x > 5)` failed");
+}
+
+#[test]
+fn check_in_chain() {
+    let v = vec![1, 2, 3];
+
+    v.value()
+        .should()
+        .have_count(3)
+        .then(|v| check!(v[2] == 4))
+        .assert_invalid_with_message("Check `v[2] == 4` failed: left `v[2]` is 3, right `4` is 4");
+}
@@ -0,0 +1,95 @@
+use checkmate::prelude::*;
+use checkmate::Checkable;
+
+#[derive(Debug, Checkable)]
+struct MyStruct {
+    attr_1: usize,
+    attr_2: String,
+    coll: Vec<f32>,
+}
+
+#[derive(Debug, Checkable)]
+struct Pair<T>(T, T);
+
+#[derive(Debug, Checkable)]
+struct Labeled<T = String, const N: usize = 2> {
+    label: T,
+    codes: [u8; N],
+}
+
+#[derive(Debug, Checkable)]
+struct Token {
+    r#type: String,
+}
+
+fn my_struct() -> MyStruct {
+    MyStruct { attr_1: 10, attr_2: String::from("hello"), coll: vec![1.2, 3.4] }
+}
+
+#[test]
+fn check_fields() {
+    my_struct()
+        .value()
+        .should()
+        .field_attr_1(|f| f.should().be(10))
+        .and()
+        .field_attr_2(|f| f.should().have_length(5).and().start_with("he"))
+        .and()
+        .field_coll(|f| f.should().have_count(2))
+        .assert_valid();
+}
+
+#[test]
+fn check_fields_err() {
+    my_struct()
+        .value()
+        .should()
+        .field_attr_1(|f| f.should().be(10))
+        .and()
+        .field_attr_2(|f| f.should().start_with("wo"))
+        .assert_invalid_with_message("attr_2: Should start with \"wo\"");
+}
+
+#[test]
+fn check_tuple_struct_fields() {
+    Pair(1, 2)
+        .value()
+        .should()
+        .field_0(|f| f.should().be(1))
+        .and()
+        .field_1(|f| f.should().be(3))
+        .assert_invalid_with_message("1: Should be '3'");
+}
+
+#[test]
+fn check_field_in_child() {
+    Some(my_struct())
+        .value()
+        .should()
+        .be_some_and()
+        .should()
+        .field_attr_1(|f| f.should().be(11))
+        .done()
+        .assert_invalid_with_message("attr_1: Should be '11'");
+}
+
+#[test]
+fn check_raw_identifier_field() {
+    Token { r#type: String::from("ident") }
+        .value()
+        .should()
+        .field_type(|f| f.should().be(String::from("literal")))
+        .assert_invalid_with_message("type: Should be '\"literal\"'");
+}
+
+#[test]
+fn check_struct_with_default_params() {
+    let labeled: Labeled = Labeled { label: String::from("id"), codes: [1, 2] };
+    labeled
+        .value()
+        .should()
+        .field_label(|f| f.should().be(String::from("id")))
+        .and()
+        .field_codes(|f| f.should().have_len(3))
+        .assert_invalid_with_message("codes: Should have length 3, got 2");
+}
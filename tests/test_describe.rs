@@ -0,0 +1,125 @@
+use checkmate::prelude::*;
+use checkmate::Checkable;
+
+#[derive(Debug, Checkable)]
+struct Address {
+    zip: String,
+}
+
+#[derive(Debug, Checkable)]
+struct Profile {
+    name: String,
+    address: Address,
+}
+
+fn profile() -> Profile {
+    Profile { name: String::from("Jane"), address: Address { zip: String::from("1234") } }
+}
+
+#[test]
+fn describe_valid() {
+    10.value().should().be(10).describe("answer").assert_valid();
+}
+
+#[test]
+fn describe_failure() {
+    10.value()
+        .should()
+        .be(11)
+        .describe("answer")
+        .assert_invalid_with_message("answer: Should be '11'");
+}
+
+#[test]
+fn describe_nested_which() {
+    profile()
+        .value()
+        .which(|p| {
+            p.address
+                .zip
+                .len()
+                .value()
+                .which(|len| (*len).value().should().be(5).describe("length"))
+                .done()
+                .describe("zip")
+        })
+        .done()
+        .describe("user profile")
+        .assert_invalid_with_message("user profile > zip > length: Should be '5'");
+}
+
+#[test]
+fn describe_nested_fields() {
+    profile()
+        .value()
+        .should()
+        .field_name(|name| name.should().start_with("J"))
+        .and()
+        .field_address(|address| address.should().field_zip(|zip| zip.should().start_with("9")))
+        .describe("user profile")
+        .assert_invalid_with_message("user profile > address > zip: Should start with \"9\"");
+}
+
+#[test]
+fn context_on_child() {
+    Some(String::from("abc"))
+        .value()
+        .should()
+        .be_some_and()
+        .should()
+        .start_with("x")
+        .context("when parsing header")
+        .done()
+        .assert_invalid_with_message("when parsing header: Should start with \"x\"");
+}
+
+#[test]
+fn describe_before_failure() {
+    10.value()
+        .describe("answer")
+        .should()
+        .be(11)
+        .assert_invalid_with_message("answer: Should be '11'");
+}
+
+#[test]
+fn describe_before_nested_failure() {
+    profile()
+        .value()
+        .describe("user profile")
+        .should()
+        .field_address(|address| address.should().field_zip(|zip| zip.should().start_with("9")))
+        .assert_invalid_with_message("user profile > address > zip: Should start with \"9\"");
+}
+
+#[test]
+fn describe_custom_message_with_colon() {
+    10.value()
+        .should()
+        .be(11)
+        .with_message("value mismatch: got 10")
+        .describe("answer")
+        .describe("config")
+        .assert_invalid_with_message("config > answer: value mismatch: got 10");
+}
+
+#[test]
+fn custom_message_keeps_trail() {
+    10.value()
+        .describe("answer")
+        .should()
+        .be(11)
+        .with_message("wrong answer")
+        .assert_invalid_with_message("answer: wrong answer");
+}
+
+#[test]
+fn trail_of_failure() {
+    let checked = profile()
+        .value()
+        .should()
+        .field_address(|address| address.should().field_zip(|zip| zip.should().start_with("9")))
+        .describe("user profile");
+
+    checked.trail().to_vec().value().should().be(vec!["user profile", "address", "zip"]).assert_valid();
+}
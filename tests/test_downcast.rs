@@ -0,0 +1,86 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt::Display;
+
+use checkmate::prelude::*;
+
+#[derive(Debug)]
+struct MyError {
+    code: usize,
+}
+
+impl Display for MyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed with code {}", self.code)
+    }
+}
+
+impl Error for MyError {}
+
+fn fails() -> Result<(), Box<dyn Error + Send + Sync>> {
+    Err(Box::new(MyError { code: 3 }))
+}
+
+#[test]
+fn downcast_error() {
+    let err = fails().unwrap_err();
+
+    err.value()
+        .should()
+        .be_of_type::<MyError>()
+        .should()
+        .have_message("failed with code 3")
+        .done()
+        .assert_valid();
+}
+
+#[test]
+fn downcast_error_field_check_err() {
+    let err = fails().unwrap_err();
+
+    err.value()
+        .should()
+        .be_of_type::<MyError>()
+        .which(|e| e.code.value().should().be(4))
+        .done()
+        .assert_invalid_with_message("Should be '4'");
+}
+
+#[test]
+fn downcast_error_wrong_type() {
+    let err: Box<dyn Error> = "abc".parse::<u8>().unwrap_err().into();
+
+    err.value()
+        .should()
+        .be_of_type::<MyError>()
+        .should()
+        .have_message("failed with code 3")
+        .done()
+        .assert_invalid_with_message(
+            "Should be of type `test_downcast::MyError`, got `ParseIntError { kind: InvalidDigit }`",
+        );
+}
+
+#[test]
+fn downcast_any() {
+    let value: Box<dyn Any> = Box::new(10_usize);
+
+    value.value()
+        .should()
+        .be_of_type::<usize>()
+        .should()
+        .be_greater_than(5)
+        .done()
+        .assert_valid();
+}
+
+#[test]
+fn downcast_any_wrong_type() {
+    let value: Box<dyn Any + Send> = Box::new("boom");
+
+    value.value()
+        .should()
+        .be_of_type::<usize>()
+        .done()
+        .assert_invalid_with_message("Should be of type `usize`, got `&str` (\"boom\")");
+}
@@ -0,0 +1,53 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use checkmate::eventually;
+use checkmate::prelude::*;
+
+#[test]
+fn eventually_valid() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let worker = count.clone();
+    thread::spawn(move || {
+        for _ in 0..10 {
+            thread::sleep(Duration::from_millis(2));
+            worker.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    eventually(|| count.load(Ordering::SeqCst))
+        .within(Duration::from_secs(5))
+        .every(Duration::from_millis(5))
+        .check(|count| count.value().should().be(10))
+        .assert_valid();
+}
+
+#[test]
+fn eventually_err() {
+    let mut attempts = 0;
+
+    eventually(|| {
+        attempts += 1;
+        attempts.min(3)
+    })
+    .within(Duration::from_millis(50))
+    .every(Duration::from_millis(10))
+    .check(|count| count.value().should().be(10))
+    .and()
+    .be(3)
+    .assert_invalid();
+
+    attempts.value().should().be_greater_than(3).assert_valid();
+}
+
+#[test]
+fn eventually_err_message() {
+    eventually(|| 7)
+        .within(Duration::ZERO)
+        .check(|count| count.value().should().be(10))
+        .assert_invalid_with_message(
+            "Should become valid within 0ns, still invalid after 1 attempts: Should be '10' (last observed value 7)",
+        );
+}
@@ -0,0 +1,91 @@
+use std::future::{pending, ready, Future};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+use checkmate::prelude::*;
+
+/// Resolves once a background thread has slept for the given duration.
+struct Delay {
+    state: Arc<Mutex<(bool, Option<Waker>)>>,
+}
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new((false, None::<Waker>)));
+        let background = state.clone();
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut state = background.lock().unwrap();
+            state.0 = true;
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        });
+        Self { state }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.0 {
+            Poll::Ready(())
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn resolve_within() {
+    async {
+        Delay::new(Duration::from_millis(10)).await;
+        42
+    }
+    .should_resolve()
+    .within(Duration::from_secs(5))
+    .should()
+    .be(42)
+    .assert_valid();
+}
+
+#[test]
+fn resolve_within_err() {
+    pending::<u32>()
+        .should_resolve()
+        .within(Duration::from_millis(20))
+        .should()
+        .be(42)
+        .assert_invalid_with_message("Future should resolve within 20ms");
+}
+
+#[test]
+fn complete_with() {
+    async { String::from("hello") }
+        .should_complete_with(|v| v.should().start_with("he"))
+        .assert_valid();
+}
+
+#[test]
+fn stay_pending_for() {
+    Delay::new(Duration::from_millis(200))
+        .should_stay_pending_for(Duration::from_millis(10))
+        .assert_valid();
+
+    ready(1)
+        .should_stay_pending_for(Duration::from_millis(10))
+        .assert_invalid_with_message("Future should stay pending for 10ms, but resolved");
+}
+
+#[test]
+fn complete_with_timeout() {
+    pending::<String>()
+        .should_complete_with(|v| v.should().start_with("he"))
+        .assert_invalid_with_message("Future should resolve within 1s");
+}
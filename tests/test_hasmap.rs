@@ -29,7 +29,7 @@ fn hashmap_iterator_err() {
     .value()
     .should()
     .contain(("d", 2), Exactly::once())
-    .assert_invalid_with_message("Iterator should contain <type does not implement Display or Debug> exactly 1 times");
+    .assert_invalid_with_message("Iterator should contain (\"d\", 2) exactly 1 times");
 }
 
 #[test]
@@ -53,3 +53,17 @@ fn hashmap_contain_value() {
 
     map.value().should().contain_value(2).assert_valid();
 }
+
+#[test]
+fn hashmap_contain_borrowed_key_err() {
+    let fixture = String::from("d");
+    let map = HashMap::from([
+        ("a", 1),
+        ("b", 2),
+    ]);
+
+    map.value()
+    .should()
+    .contain_key(fixture.as_str())
+    .assert_invalid_with_message("Should contain key \"d\"");
+}
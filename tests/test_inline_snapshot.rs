@@ -0,0 +1,77 @@
+use std::env;
+use std::fs;
+
+use checkmate::prelude::*;
+use checkmate::snapshot::{apply_inline_snapshots, pending_inline_path, update_mode};
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn match_inline_snapshot() {
+    Point { x: 1, y: 2 }
+        .value()
+        .should()
+        .match_inline_snapshot(r#"
+            Point {
+                x: 1,
+                y: 2,
+            }
+        "#)
+        .assert_valid();
+
+    String::from("hello")
+        .value()
+        .should()
+        .match_inline_display_snapshot("hello")
+        .assert_valid();
+}
+
+#[test]
+fn match_inline_snapshot_err() {
+    if update_mode() {
+        return;
+    }
+
+    Point { x: 1, y: 3 }
+        .value()
+        .should()
+        .match_inline_snapshot(r#"
+            Point {
+                x: 1,
+                y: 2,
+            }
+        "#)
+        .assert_invalid();
+}
+
+#[test]
+fn apply_pending_snapshots() {
+    let dir = env::temp_dir().join(format!("checkmate-inline-{}", std::process::id()));
+    fs::create_dir_all(dir.join("snapshots")).unwrap();
+    let source = dir.join("test_source.rs");
+    fs::write(
+        &source,
+        "fn check() {\n    a.value().should().match_inline_snapshot(\"old\");\n    b.value().should().match_inline_snapshot(r#\"\n        Old\n    \"#);\n}\n",
+    )
+    .unwrap();
+    fs::write(pending_inline_path(&source), "@@ 2:24 1\nnew \"value\"\n@@ 3:24 2\nNew {\n}\n").unwrap();
+
+    apply_inline_snapshots(&source).unwrap().value().should().be(2).assert_valid();
+
+    fs::read_to_string(&source)
+        .unwrap()
+        .value()
+        .should()
+        .be(String::from(
+            "fn check() {\n    a.value().should().match_inline_snapshot(r#\"new \"value\"\"#);\n    b.value().should().match_inline_snapshot(r#\"\nNew {\n}\n\"#);\n}\n",
+        ))
+        .assert_valid();
+    pending_inline_path(&source).exists().value().should().be_false("Pending file should be removed").assert_valid();
+
+    fs::remove_dir_all(dir).unwrap();
+}
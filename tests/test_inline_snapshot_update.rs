@@ -0,0 +1,42 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use checkmate::prelude::*;
+use checkmate::snapshot::{apply_inline_snapshots, pending_inline_path, UPDATE_ENV_VAR};
+
+// Update mode is process wide, so this binary holds a single test
+#[test]
+fn record_pending_snapshot_from_caller_location() {
+    env::set_var(UPDATE_ENV_VAR, "1");
+    let this_file = Path::new(env!("CARGO_MANIFEST_DIR")).join(file!());
+    let pending = pending_inline_path(&this_file);
+    let _ = fs::remove_file(&pending);
+
+    String::from("new")
+        .value()
+        .should()
+        .match_inline_display_snapshot("old")
+        .assert_valid();
+
+    let recorded = fs::read_to_string(&pending).unwrap();
+    fs::remove_file(&pending).unwrap();
+
+    // Apply the recorded entry to a copy of this file, so the line and column
+    // of the caller location are checked against the literal they point at
+    let dir = env::temp_dir().join(format!("checkmate-inline-update-{}", std::process::id()));
+    let copy = dir.join("test_inline_snapshot_update.rs");
+    fs::create_dir_all(dir.join("snapshots")).unwrap();
+    fs::copy(&this_file, &copy).unwrap();
+    fs::write(pending_inline_path(&copy), recorded).unwrap();
+
+    apply_inline_snapshots(&copy).unwrap().value().should().be(1).assert_valid();
+    fs::read_to_string(&copy)
+        .unwrap()
+        .value()
+        .should()
+        .contain_substring(".match_inline_display_snapshot(r#\"new\"#)")
+        .assert_valid();
+
+    fs::remove_dir_all(dir).unwrap();
+}
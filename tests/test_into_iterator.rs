@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use checkmate::prelude::*;
+use checkmate::times::{AtLeast, AtMost, Exactly};
+use checkmate::Collection;
+
+/// Not `Clone`, so checks have to iterate it by reference.
+#[derive(Debug)]
+struct Names(Vec<String>);
+
+impl<'a> IntoIterator for &'a Names {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+fn names() -> Names {
+    Names(vec![String::from("ada"), String::from("grace"), String::from("barbara")])
+}
+
+#[test]
+fn count_without_clone() {
+    names().value().should().have_count(3).and().not_be_empty().assert_valid();
+}
+
+#[test]
+fn count_without_clone_err() {
+    names()
+        .value()
+        .should()
+        .be_empty()
+        .assert_invalid_with_message("Iterator should be empty");
+}
+
+#[test]
+fn contain_without_clone() {
+    names()
+        .value()
+        .should()
+        .contain_all_of(["ada", "grace"])
+        .and()
+        .contain_any_of(["linus", "barbara"])
+        .and()
+        .contain_none_of(["linus"])
+        .assert_valid();
+}
+
+#[test]
+fn contain_all_of_err() {
+    names()
+        .value()
+        .should()
+        .contain_all_of(["ada", "linus"])
+        .assert_invalid_with_message("Iterator should contain all of [\"ada\", \"linus\"]");
+}
+
+#[test]
+fn contain_borrowed_items() {
+    let owned = [String::from("a"), String::from("b")];
+    let borrowed: Vec<&str> = owned.iter().map(String::as_str).collect();
+
+    borrowed
+        .value()
+        .should()
+        .contain_any_of(["b"])
+        .and()
+        .contain_none_of(["c"])
+        .assert_valid();
+}
+
+#[test]
+fn count_of_range() {
+    (0..5).value().should().have_count(5).and().not_be_empty().assert_valid();
+}
+
+#[test]
+fn contain_chars() {
+    "abc".chars().value().should().contain_all_of(['a']).and().contain_none_of(['z']).assert_valid();
+}
+
+#[test]
+fn contain_chars_err() {
+    "abc"
+        .chars()
+        .value()
+        .should()
+        .contain_any_of(['x'])
+        .assert_invalid_with_message("Iterator should contain any of ['x']");
+}
+
+/// Wraps its items without implementing `IntoIterator` itself.
+#[derive(Debug)]
+struct Scores {
+    values: Vec<u32>,
+}
+
+struct ByScores;
+
+impl Collection<ByScores> for Scores {
+    type Target = [u32];
+
+    fn collection(&self) -> impl std::ops::Deref<Target = [u32]> + '_ {
+        &self.values[..]
+    }
+}
+
+#[test]
+fn custom_collection() {
+    Scores { values: vec![3, 5, 8] }
+        .value()
+        .should()
+        .have_count(3)
+        .and()
+        .contain_all_of([5, 8])
+        .assert_valid();
+}
+
+#[test]
+fn contain_map_entries() {
+    let scores = HashMap::from([("ada", 3), ("grace", 5)]);
+
+    scores
+        .value()
+        .should()
+        .contain_any_of([("ada", 3)])
+        .and()
+        .contain_all_of([("ada", 3), ("grace", 5)])
+        .and()
+        .contain_none_of([("ada", 5)])
+        .and()
+        .contain(("grace", 5), Exactly::once())
+        .assert_valid();
+}
+
+#[test]
+fn contain_map_entries_err() {
+    HashMap::from([("ada", 3)])
+        .value()
+        .should()
+        .contain_any_of([("ada", 4)])
+        .assert_invalid_with_message("Iterator should contain any of [(\"ada\", 4)]");
+}
+
+#[test]
+fn contain_without_clone_counted() {
+    names().value().should().contain("ada", Exactly::once()).and().contain("linus", AtMost::once()).assert_valid();
+}
+
+#[test]
+fn contain_in_range() {
+    (0..5)
+        .value()
+        .should()
+        .contain(3, Exactly::once())
+        .and()
+        .contain(7, AtLeast::once())
+        .assert_invalid_with_message("Iterator should contain 7 at least 1 times");
+}
@@ -0,0 +1,58 @@
+use std::fs::File;
+use std::io::{self, ErrorKind};
+
+use checkmate::prelude::*;
+
+#[test]
+fn io_error_kind() {
+    let err = io::Error::new(ErrorKind::NotFound, "no such file");
+
+    err.value()
+        .should()
+        .have_kind(ErrorKind::NotFound)
+        .and()
+        .have_message("no such file")
+        .assert_valid();
+}
+
+#[test]
+fn io_error_kind_err() {
+    let err = io::Error::new(ErrorKind::PermissionDenied, "denied");
+
+    err.value()
+        .should()
+        .have_kind(ErrorKind::NotFound)
+        .assert_invalid_with_message("Error kind should be NotFound, got PermissionDenied");
+}
+
+#[test]
+fn io_error_raw_os_error() {
+    io::Error::from_raw_os_error(2)
+        .value()
+        .should()
+        .have_raw_os_error(2)
+        .assert_valid();
+
+    io::Error::other("custom")
+        .value()
+        .should()
+        .have_raw_os_error(2)
+        .assert_invalid_with_message("Raw OS error should be 2, got none");
+}
+
+#[test]
+fn io_result_fail_with_kind() {
+    File::open("/this/path/does/not/exist")
+        .value()
+        .should()
+        .fail_with_kind(ErrorKind::NotFound)
+        .assert_valid();
+
+    let result: io::Result<usize> = Ok(1);
+
+    result
+        .value()
+        .should()
+        .fail_with_kind(ErrorKind::NotFound)
+        .assert_invalid_with_message("Should be Error with kind NotFound, got Ok");
+}
@@ -0,0 +1,84 @@
+use checkmate::fields::{anything, ge, gt, lt, ne};
+use checkmate::match_fields;
+use checkmate::prelude::*;
+
+#[derive(Debug)]
+struct Address {
+    city: String,
+    zip: String,
+}
+
+#[derive(Debug)]
+struct Order {
+    id: u32,
+    total: f64,
+}
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+    admin: bool,
+    address: Address,
+    orders: Vec<Order>,
+    tags: Vec<&'static str>,
+}
+
+fn user() -> User {
+    User {
+        name: String::from("bob"),
+        age: 42,
+        admin: false,
+        address: Address { city: String::from("Utrecht"), zip: String::from("3511") },
+        orders: vec![Order { id: 1, total: 9.5 }, Order { id: 2, total: 120.0 }],
+        tags: vec!["new", "beta"],
+    }
+}
+
+#[test]
+fn match_subset_of_fields() {
+    match_fields!(user().value(), { name: "bob", age: gt(18) }).assert_valid();
+}
+
+#[test]
+fn match_nested_fields() {
+    match_fields!(user().value(), {
+        age: ge(42),
+        admin: false,
+        address: { city: "Utrecht", zip: ne("") },
+        orders: [{ id: 1, total: lt(10.0) }, { id: 2, total: anything() }],
+        tags: ["new", "beta"],
+    })
+    .then(|user| user.name.len().value().should().be(3))
+    .assert_valid();
+}
+
+#[test]
+fn report_every_mismatch() {
+    match_fields!(user().value(), {
+        name: "alice",
+        age: lt(18),
+        address: { zip: "1234" },
+        orders: [{ id: 1 }],
+        tags: ["new", "alpha", "old"],
+    })
+    .assert_invalid_with_message(
+        "6 fields did not match:
+name: Should be \"alice\", got \"bob\"
+age: Should be < 18, got 42
+address.zip: Should be \"1234\", got \"3511\"
+orders: Should have 1 element, got 2
+tags[1]: Should be \"alpha\", got \"beta\"
+tags[2]: Should exist, got 2 elements",
+    );
+}
+
+#[test]
+fn report_missing_elements() {
+    match_fields!(user().value(), { orders: [{ id: 1 }, { id: 2 }, { id: 3 }], tags: ["new"] })
+        .assert_invalid_with_message(
+            "2 fields did not match:
+orders[2]: Should exist, got 2 elements
+tags: Should have 1 element, got 2",
+        );
+}
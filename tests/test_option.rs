@@ -0,0 +1,29 @@
+use checkmate::prelude::*;
+
+#[test]
+fn be_some_and() {
+    Some(String::from("hello"))
+        .value()
+        .should()
+        .be_some_and()
+        .should()
+        .start_with("he")
+        .and()
+        .have_length(5)
+        .done()
+        .assert_valid();
+}
+
+#[test]
+fn be_some_and_err() {
+    let value: Option<String> = None;
+
+    value
+        .value()
+        .should()
+        .be_some_and()
+        .should()
+        .start_with("he")
+        .done()
+        .assert_invalid_with_message("Option should be Some, got None");
+}
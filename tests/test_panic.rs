@@ -0,0 +1,86 @@
+use std::panic::panic_any;
+
+use checkmate::prelude::*;
+
+fn parse(value: &str) -> u32 {
+    value.parse().expect("value should be a number")
+}
+
+#[derive(Debug, PartialEq)]
+struct Overflow(u32);
+
+#[test]
+fn panic() {
+    (|| parse("bad")).value().should().panic().assert_valid();
+
+    (|| parse("10"))
+        .value()
+        .should()
+        .panic()
+        .assert_invalid_with_message("Should panic");
+}
+
+#[test]
+fn panic_with_message_containing() {
+    (|| parse("bad"))
+        .value()
+        .should()
+        .panic_with_message_containing("should be a number")
+        .assert_valid();
+
+    (|| parse("bad"))
+        .value()
+        .should()
+        .panic_with_message_containing("overflow")
+        .assert_invalid_with_message(
+            "Should panic with a message containing \"overflow\", got \"value should be a number: ParseIntError { kind: InvalidDigit }\"",
+        );
+
+    (|| panic_any(Overflow(3)))
+        .value()
+        .should()
+        .panic_with_message_containing("overflow")
+        .assert_invalid_with_message(
+            "Should panic with a message containing \"overflow\", got a value of another type",
+        );
+}
+
+#[test]
+fn not_panic() {
+    (|| parse("10")).value().should().not_panic().assert_valid();
+
+    (|| panic!("boom"))
+        .value()
+        .should()
+        .not_panic()
+        .assert_invalid_with_message("Should not panic, got `&str` (\"boom\")");
+}
+
+#[test]
+fn panic_payload() {
+    (|| panic_any(Overflow(3)))
+        .value()
+        .should()
+        .panic_and()
+        .should()
+        .be_of_type::<Overflow>()
+        .should()
+        .be(Overflow(3))
+        .done()
+        .assert_valid();
+}
+
+#[test]
+fn closure_called_per_check() {
+    let mut calls = 0;
+
+    (|| calls += 1)
+        .value()
+        .should()
+        .not_panic()
+        .and()
+        .not_panic()
+        .assert_valid();
+
+    calls.value().should().be(2).assert_valid();
+}
@@ -73,4 +73,74 @@ fn test_err() {
     .should()
     .be(MyEnum::OptionB)
     .assert_invalid_with_message("Should be 'OptionB'");
-}
\ No newline at end of file
+}
+
+#[test]
+fn be_one_of_err_lists_values() {
+    9
+    .value()
+    .should()
+    .be_one_of([11, 12, 10])
+    .assert_invalid_with_message("Should be one of [11, 12, 10]");
+}
+
+#[test]
+fn with_message_fn_not_called_when_valid() {
+    11
+    .value()
+    .should()
+    .be(11)
+    .with_message_fn(|| panic!("message should not be built"))
+    .assert_valid();
+}
+
+#[test]
+fn with_message_fn_err() {
+    let expected = 12;
+
+    11
+    .value()
+    .should()
+    .be(expected)
+    .with_message_fn(|| format!("This value should be '{expected}'"))
+    .assert_invalid_with_message("This value should be '12'");
+}
+
+#[test]
+fn with_message_fn_child_err() {
+    Some(11)
+    .value()
+    .should()
+    .be_some_and()
+    .should()
+    .be(12)
+    .with_message_fn(|| "Inner value should be '12'".to_string())
+    .done()
+    .assert_invalid_with_message("Inner value should be '12'");
+}
+
+#[test]
+fn be_borrowed() {
+    let fixture = String::from("hello");
+    let expected = fixture.as_str();
+
+    "hello"
+    .value()
+    .should()
+    .be(expected)
+    .and()
+    .be_one_of([expected, "world"])
+    .assert_valid();
+}
+
+#[test]
+fn be_greater_than_borrowed_err() {
+    let limits = [10, 20];
+    let limit = &limits[1];
+
+    (&15)
+    .value()
+    .should()
+    .be_greater_than(limit)
+    .assert_invalid_with_message("Should be > 20");
+}
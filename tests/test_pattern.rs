@@ -0,0 +1,47 @@
+use checkmate::prelude::*;
+use checkmate::should_match;
+
+#[derive(Debug, Clone, PartialEq)]
+enum State {
+    Running { retries: u32, name: String },
+    Stopped,
+}
+
+#[test]
+fn match_variant() {
+    let state = State::Running { retries: 1, name: String::from("worker") };
+
+    should_match!(state.value(), State::Running { retries, .. } if *retries < 3)
+        .and()
+        .be(State::Running { retries: 1, name: String::from("worker") })
+        .assert_valid();
+}
+
+#[test]
+fn match_variant_err() {
+    should_match!(State::Stopped.value(), State::Running { .. })
+        .assert_invalid_with_message("Should match pattern `State::Running { .. }`, got Stopped");
+}
+
+#[test]
+fn match_guard_err() {
+    let state = State::Running { retries: 5, name: String::from("worker") };
+
+    should_match!(state.value(), State::Running { retries, .. } if *retries < 3)
+        .assert_invalid_with_message(
+            "Should match pattern `State::Running { retries, .. } if *retries < 3`, got Running { retries: 5, name: \"worker\" }",
+        );
+}
+
+#[test]
+fn match_in_chain() {
+    let states = vec![State::Stopped, State::Stopped];
+
+    states
+        .value()
+        .should()
+        .have_count(2)
+        .which(|states| should_match!(states[0].clone().value(), State::Stopped | State::Running { .. }))
+        .done()
+        .assert_valid();
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use checkmate::prelude::*;
+use checkmate::property::{any, between, for_all, maps, strings, vecs, Generator};
+
+#[test]
+fn for_all_valid() {
+    for_all(vecs(any::<i32>()))
+        .check(|v| {
+            let mut reversed = v.clone();
+            reversed.reverse();
+            reversed.reverse();
+            reversed.value().should().be(v)
+        })
+        .assert_valid();
+}
+
+#[test]
+fn for_all_shrinks_integer() {
+    for_all(between(0_u32, 1000))
+        .seed(7)
+        .check(|x| x.value().should().be_smaller_than(100).with_message("Should be small"))
+        .assert_invalid_with_message(
+            "Property failed for 100 (case 1, shrunk 3 times, rerun with CHECKMATE_SEED=7): Should be small",
+        );
+}
+
+#[test]
+fn for_all_shrinks_vec() {
+    for_all(vecs(between(0_u8, 50)))
+        .seed(42)
+        .check(|v| v.value().should().contain_none_of([13]).with_message("Should not contain 13"))
+        .assert_invalid_with_message(
+            "Property failed for [13] (case 1, shrunk 5 times, rerun with CHECKMATE_SEED=42): Should not contain 13",
+        );
+}
+
+#[test]
+fn for_all_tuples_and_maps() {
+    for_all((strings().max_len(8), maps(any::<u8>(), any::<bool>())))
+        .cases(50)
+        .check(|(s, map)| {
+            s.chars().count().value().should().be_smaller_than(9).then(|_| {
+                map.len().value().should().be_smaller_than(33)
+            })
+        })
+        .assert_valid();
+}
+
+#[test]
+fn map_shrinking_ignores_iteration_order() {
+    let forward: HashMap<u8, bool> = (0..20).map(|key| (key, key % 2 == 0)).collect();
+    let backward: HashMap<u8, bool> = (0..20).rev().map(|key| (key, key % 2 == 0)).collect();
+    let generator = maps(any::<u8>(), any::<bool>());
+
+    generator.shrink(&forward).value().should().be(generator.shrink(&backward)).assert_valid();
+}
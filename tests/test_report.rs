@@ -0,0 +1,36 @@
+use std::env;
+use std::fs;
+
+use checkmate::prelude::*;
+use checkmate::report::REPORT_ENV_VAR;
+
+/// The reporter reads its configuration once per process, so this is the
+/// only test in this file.
+#[test]
+fn report_json_lines() {
+    let path = env::temp_dir().join(format!("checkmate-report-{}.jsonl", std::process::id()));
+    fs::write(&path, "left over from a previous run\n").unwrap();
+    env::set_var(REPORT_ENV_VAR, &path);
+
+    10.value().should().be(10).assert_valid();
+    10.value().should().be(11).assert_invalid_with_message("Should be '11'");
+
+    let report = fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = report.lines().collect();
+
+    lines.len().value().should().be(2).assert_valid();
+    lines[0]
+        .to_string()
+        .value()
+        .should()
+        .start_with("{\"test\":\"report_json_lines\",\"location\":\"tests/test_report.rs:15:32\",\"passed\":true,\"message\":null,")
+        .assert_valid();
+    lines[1]
+        .to_string()
+        .value()
+        .should()
+        .start_with("{\"test\":\"report_json_lines\",\"location\":\"tests/test_report.rs:16:32\",\"passed\":true,\"message\":\"Should be '11'\",")
+        .assert_valid();
+
+    fs::remove_file(&path).unwrap();
+}
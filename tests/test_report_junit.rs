@@ -0,0 +1,29 @@
+use std::env;
+use std::fs;
+
+use checkmate::prelude::*;
+use checkmate::report::REPORT_ENV_VAR;
+
+/// The reporter reads its configuration once per process, so this is the
+/// only test in this file.
+#[test]
+fn report_junit() {
+    let path = env::temp_dir().join(format!("checkmate-report-{}.xml", std::process::id()));
+    env::set_var(REPORT_ENV_VAR, &path);
+
+    10.value().should().be(10).assert_valid();
+    10.value().should().be(11).assert_invalid_with_message("Should be '11'");
+
+    let report = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    report
+        .value()
+        .should()
+        .contain_substring("<testsuite name=\"test_report_junit\" tests=\"2\" failures=\"0\"")
+        .and()
+        .contain_substring("<testcase classname=\"report_junit\" name=\"tests/test_report_junit.rs:14:32\"")
+        .and()
+        .contain_substring("<testcase classname=\"report_junit\" name=\"tests/test_report_junit.rs:15:32\"")
+        .assert_valid();
+}
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use checkmate::prelude::*;
+use checkmate::report::{to_junit, Record};
+
+// Reporting is not enabled in this file, the checks below only render
+#[test]
+fn render_junit() {
+    let records = vec![
+        Record {
+            test: String::from("my_test"),
+            location: String::from("tests/my_test.rs:3:5"),
+            passed: true,
+            message: None,
+            elapsed: Duration::from_millis(1),
+        },
+        Record {
+            test: String::from("my_test"),
+            location: String::from("tests/my_test.rs:4:5"),
+            passed: false,
+            message: Some(String::from("Should be '<b>'")),
+            elapsed: Duration::from_millis(2),
+        },
+    ];
+
+    to_junit("suite", &records)
+        .value()
+        .should()
+        .be("<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<testsuites>
+  <testsuite name=\"suite\" tests=\"2\" failures=\"1\" time=\"0.003\">
+    <testcase classname=\"my_test\" name=\"tests/my_test.rs:3:5\" time=\"0.001\"/>
+    <testcase classname=\"my_test\" name=\"tests/my_test.rs:4:5\" time=\"0.002\">
+      <failure message=\"Should be &apos;&lt;b&gt;&apos;\">Should be &apos;&lt;b&gt;&apos;</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+")
+        .assert_valid();
+}
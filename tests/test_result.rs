@@ -0,0 +1,111 @@
+use checkmate::prelude::*;
+
+fn parse(value: &str) -> Result<u32, std::num::ParseIntError> {
+    value.parse()
+}
+
+#[test]
+fn be_ok_and() {
+    parse("42")
+        .value()
+        .should()
+        .be_ok_and()
+        .should()
+        .be(42)
+        .done()
+        .assert_valid();
+}
+
+#[test]
+fn be_ok_and_err() {
+    parse("abc")
+        .value()
+        .should()
+        .be_ok_and()
+        .should()
+        .be(42)
+        .done()
+        .assert_invalid_with_message("Should be Ok, got Err(ParseIntError { kind: InvalidDigit })");
+}
+
+#[test]
+fn be_ok_and_inner_check_err() {
+    parse("41")
+        .value()
+        .should()
+        .be_ok_and()
+        .should()
+        .be(42)
+        .done()
+        .assert_invalid_with_message("Should be '42'");
+}
+
+#[test]
+fn be_err_and() {
+    parse("abc")
+        .value()
+        .should()
+        .be_err_and()
+        .should()
+        .have_message("invalid digit found in string")
+        .done()
+        .and()
+        .be_err()
+        .assert_valid();
+
+    parse("42")
+        .value()
+        .should()
+        .be_err_and()
+        .done()
+        .assert_invalid_with_message("Should be Error, got Ok(42)");
+}
+
+#[test]
+fn be_ok_with() {
+    parse("42").value().should().be_ok_with(42).assert_valid();
+
+    parse("41")
+        .value()
+        .should()
+        .be_ok_with(42)
+        .assert_invalid_with_message("Should be Ok(42), got Ok(41)");
+
+    parse("")
+        .value()
+        .should()
+        .be_ok_with(42)
+        .assert_invalid_with_message("Should be Ok(42), got Err(ParseIntError { kind: Empty })");
+}
+
+#[test]
+fn be_err_with() {
+    let result: Result<u32, &str> = Err("not found");
+
+    result.value().should().be_err_with("not found").assert_valid();
+
+    let result: Result<u32, &str> = Ok(3);
+
+    result
+        .value()
+        .should()
+        .be_err_with("not found")
+        .assert_invalid_with_message("Should be Err(\"not found\"), got Ok(3)");
+}
+
+#[test]
+fn be_err_satisfying() {
+    parse("abc")
+        .value()
+        .should()
+        .be_err_satisfying(|e| e.to_string().contains("invalid digit"))
+        .assert_valid();
+
+    parse("")
+        .value()
+        .should()
+        .be_err_satisfying(|e| e.to_string().contains("invalid digit"))
+        .assert_invalid_with_message(
+            "Should be Err satisfying the predicate, got Err(ParseIntError { kind: Empty })",
+        );
+}
@@ -0,0 +1,100 @@
+use checkmate::prelude::*;
+
+#[test]
+fn have_len() {
+    [1, 2, 3].value().should().have_len(3).assert_valid();
+    vec![1, 2].value().should().have_len(3).assert_invalid_with_message("Should have length 3, got 2");
+}
+
+#[test]
+fn element_at() {
+    vec![String::from("a"), String::from("b")]
+        .value()
+        .should()
+        .element_at(1)
+        .should()
+        .be("b")
+        .done()
+        .assert_valid();
+}
+
+#[test]
+fn element_at_out_of_bounds() {
+    [1, 2]
+        .value()
+        .should()
+        .element_at(3)
+        .should()
+        .be(1)
+        .done()
+        .assert_invalid_with_message("index 3 out of bounds (len 2)");
+}
+
+#[test]
+fn first_and_last() {
+    let values = [3, 1, 4];
+    let slice = &values[..];
+
+    slice
+        .value()
+        .should()
+        .first()
+        .should()
+        .be(&3)
+        .done()
+        .should()
+        .last()
+        .should()
+        .be(&5)
+        .done()
+        .assert_invalid_with_message("Should be '5'");
+}
+
+/// Not `Clone`, so only a borrowed slice can hand out its elements.
+#[derive(Debug, PartialEq)]
+struct Token(&'static str);
+
+#[test]
+fn element_of_borrowed_slice() {
+    let tokens = vec![Token("let"), Token("x")];
+
+    (&tokens)
+        .value()
+        .should()
+        .element_at(1)
+        .should()
+        .be(&Token("y"))
+        .done()
+        .assert_invalid_with_message("Should be 'Token(\"y\")'");
+    (&tokens[..]).value().should().first().should().be(&Token("let")).done().assert_valid();
+}
+
+#[test]
+fn first_of_empty() {
+    Vec::<u8>::new()
+        .value()
+        .should()
+        .first()
+        .done()
+        .assert_invalid_with_message("Should have a first element, got an empty slice");
+}
+
+#[test]
+fn contain_slice() {
+    vec![1, 2, 3, 4].value().should().contain_slice(&[2, 3]).assert_valid();
+    vec![1, 2, 3, 4]
+        .value()
+        .should()
+        .contain_slice(&[3, 2])
+        .assert_invalid_with_message("Should contain slice [3, 2]");
+}
+
+#[test]
+fn be_permutation_of() {
+    [1, 2, 2, 3].value().should().be_permutation_of(&[2, 3, 2, 1]).assert_valid();
+    [1, 2, 2]
+        .value()
+        .should()
+        .be_permutation_of(&[1, 1, 2])
+        .assert_invalid_with_message("Should be a permutation of [1, 1, 2], got [1, 2, 2]");
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use checkmate::prelude::*;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+    tags: Vec<&'static str>,
+}
+
+#[test]
+fn match_debug_snapshot() {
+    let user = User { name: String::from("bob"), age: 42, tags: vec!["admin"] };
+
+    user.value().should().match_snapshot("user").assert_valid();
+}
+
+#[test]
+fn match_display_snapshot() {
+    String::from("hello\nworld")
+        .value()
+        .should()
+        .match_display_snapshot("greeting")
+        .assert_valid();
+}
+
+#[test]
+fn match_custom_snapshot() {
+    let map = BTreeMap::from([("a", 1), ("b", 2)]);
+
+    map.value()
+        .should()
+        .match_snapshot_with("map", |map| {
+            map.iter().map(|(k, v)| format!("{k}={v}")).collect::<Vec<_>>().join("\n")
+        })
+        .assert_valid();
+}
+
+#[test]
+fn snapshot_mismatch() {
+    if checkmate::snapshot::update_mode() {
+        return;
+    }
+
+    let user = User { name: String::from("alice"), age: 42, tags: vec!["admin"] };
+
+    user.value()
+        .should()
+        .match_snapshot("user")
+        .assert_invalid_with_message(
+            "Should match snapshot `tests/snapshots/test_snapshot__user.snap`:
+  User {
+-     name: \"bob\",
++     name: \"alice\",
+      age: 42,
+      tags: [
+          \"admin\",
+      ],
+  }
+",
+        );
+}
+
+#[test]
+fn snapshot_missing() {
+    if checkmate::snapshot::update_mode() {
+        return;
+    }
+
+    1.value()
+        .should()
+        .match_snapshot("missing")
+        .assert_invalid_with_message(
+            "Snapshot `tests/snapshots/test_snapshot__missing.snap` does not exist, run with CHECKMATE_UPDATE_SNAPSHOTS=1 to create it",
+        );
+}
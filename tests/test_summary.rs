@@ -0,0 +1,63 @@
+use checkmate::prelude::*;
+use checkmate::summary::Summary;
+
+fn scenario() -> Summary {
+    let mut summary = Summary::new();
+    summary.check("answer", 42.value().should().be(42));
+    summary.scope("parsing", |scope| {
+        scope.check("port", "80a".parse::<u16>().value().should().be_ok());
+        scope.scope("config", |scope| {
+            scope.check("retries", Some(3).value().describe("limits").should().be_some_and().should().be(4).describe("max"));
+        });
+    });
+    summary
+}
+
+#[test]
+fn count_checks() {
+    let summary = scenario();
+
+    summary.total().value().should().be(3).assert_valid();
+    summary.failed().value().should().be(2).assert_valid();
+}
+
+#[test]
+fn render_tap() {
+    let expected = "TAP version 14
+1..2
+ok 1 - answer
+# Subtest: parsing
+    1..2
+    not ok 1 - port
+      ---
+      message: \"Should be Ok\"
+      ...
+    # Subtest: config
+        1..1
+        not ok 1 - retries
+          ---
+          message: \"Should be '4'\"
+          path: \"limits > max\"
+          ...
+    not ok 2 - config
+not ok 2 - parsing
+";
+    scenario().to_tap().value().should().be(expected).assert_valid();
+}
+
+#[test]
+fn render_summary() {
+    scenario()
+        .into_checked()
+        .assert_invalid_with_message(
+            "3 checks, 2 failed\n\nparsing\n  ✗ port: Should be Ok\n\nparsing > config\n  ✗ retries > limits > max: Should be '4'",
+        );
+}
+
+#[test]
+fn render_summary_in_colour() {
+    let mut summary = Summary::new();
+    summary.check("answer", 42.value().should().be(42));
+
+    summary.to_summary(true).value().should().be("\x1b[32m1 check, all passed\x1b[0m\n".to_string()).assert_valid();
+}